@@ -14,6 +14,7 @@ mod loader;
 mod mcp;
 mod oauth;
 mod rate_limit;
+mod secret;
 mod server;
 mod telemetry;
 mod tls;
@@ -31,21 +32,22 @@ pub use headers::{
 pub use health::HealthConfig;
 pub use http_types::{HeaderName, HeaderValue};
 pub use llm::{
-    ApiModelConfig, ApiProviderConfig, BedrockModelConfig, BedrockProviderConfig, LlmConfig, LlmProviderConfig,
-    ModelConfig, ProviderType,
+    ApiModelConfig, ApiProviderConfig, AzureProviderConfig, BedrockModelConfig, BedrockProviderConfig, LlmAuthConfig,
+    LlmConfig, LlmProviderConfig, Modality, ModelAccessConfig, ModelConfig, OpenAiCompatibleProviderConfig,
+    ProviderAccessConfig, ProviderType, is_country_allowed, is_group_allowed,
 };
 pub use mcp::{
     ClientAuthConfig, HttpConfig, HttpProtocol, McpConfig, McpServer, McpServerRateLimit, StdioConfig, StdioTarget,
-    StdioTargetType, TlsClientConfig,
+    StdioTargetType,
 };
 pub use oauth::{OauthConfig, ProtectedResourceConfig};
 pub use rate_limit::*;
 use serde::Deserialize;
 pub use server::ServerConfig;
-pub use telemetry::exporters::{ExportersConfig, OtlpExporterConfig};
-pub use telemetry::tracing::TracingConfig;
-pub use telemetry::{LogsConfig, MetricsConfig, TelemetryConfig};
-pub use tls::TlsServerConfig;
+pub use telemetry::exporters::{ExportersConfig, OtlpExporterConfig, PrometheusExporterConfig, StdoutExporterConfig};
+pub use telemetry::tracing::{PropagationConfig, TracingConfig};
+pub use telemetry::{LogSeverity, LogsConfig, MetricsConfig, MetricViewConfig, TelemetryConfig};
+pub use tls::{RootCertificateSource, TlsClientConfig, TlsServerConfig, apply_to_reqwest_builder};
 
 /// Main configuration structure for the Nexus application.
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -108,8 +110,12 @@ mod tests {
                     storage: Memory,
                     global: None,
                     per_ip: None,
+                    resolver: None,
+                    response_headers: None,
+                    on_storage_error: FailClosed,
                 },
                 client_identification: None,
+                trusted_proxies: [],
             },
             mcp: McpConfig {
                 enabled: true,
@@ -123,9 +129,12 @@ mod tests {
                 headers: [],
             },
             llm: LlmConfig {
+                version: 1,
                 enabled: true,
                 path: "/llm",
                 providers: {},
+                auth: None,
+                country_header: "X-Country-Code",
             },
             telemetry: None,
         }
@@ -2,12 +2,16 @@
 
 use std::borrow::Cow;
 use std::collections::BTreeMap;
+use std::time::Duration;
 
-use crate::headers::HeaderRule;
-use crate::rate_limit::TokenRateLimitsConfig;
+use duration_str::deserialize_duration;
 use secrecy::SecretString;
 use serde::{Deserialize, Deserializer};
 
+use crate::headers::HeaderRule;
+use crate::rate_limit::TokenRateLimitsConfig;
+use crate::tls::TlsClientConfig;
+
 /// Configuration for an individual model within API-based providers.
 #[derive(Debug, Clone, Deserialize)]
 pub struct ApiModelConfig {
@@ -21,6 +25,52 @@ pub struct ApiModelConfig {
     /// Header transformation rules for this model.
     #[serde(default)]
     pub headers: Vec<HeaderRule>,
+    /// Whether this model supports fill-in-the-middle (FIM) completion.
+    /// Only meaningful for providers that implement a FIM endpoint (e.g. Mistral).
+    #[serde(default)]
+    pub fim: bool,
+    /// Access control policy for this model.
+    #[serde(default)]
+    pub access: Option<ModelAccessConfig>,
+    /// Maximum number of output tokens this model will generate.
+    /// When unset, falls back to the provider's built-in default for the model.
+    /// Enforced pre-flight: requests whose `max_tokens` exceeds this are rejected with 400
+    /// instead of being forwarded upstream.
+    #[serde(default)]
+    pub max_tokens: Option<u32>,
+    /// Total context window size (input + output tokens) this model supports.
+    /// When unset, falls back to the provider's built-in default for the model.
+    /// Enforced pre-flight: requests whose estimated input plus requested output tokens
+    /// exceeds this are rejected with 400 instead of being forwarded upstream.
+    #[serde(default)]
+    pub context_window: Option<u32>,
+    /// Azure OpenAI deployment name this model routes to.
+    /// Required for models belonging to an [`LlmProviderConfig::Azure`] provider; controls the
+    /// upstream path segment while `rename` (if set) continues to control the name exposed to clients.
+    #[serde(default)]
+    pub deployment_id: Option<String>,
+    /// Content modality this model produces. Determines whether configured rate
+    /// limits (see [`TokenRateLimitsConfig`]) are enforced by input token count
+    /// (`text`) or by request count (`image`, `audio`).
+    #[serde(default, rename = "type")]
+    pub modality: Modality,
+}
+
+/// Content modality a model produces.
+///
+/// Text-generation models are rate limited by input token count; non-text
+/// models (image, audio generation) have no meaningful token count and are
+/// instead rate limited by request count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum Modality {
+    /// Text generation, e.g. chat completions. The default.
+    #[default]
+    Text,
+    /// Image generation.
+    Image,
+    /// Audio generation or transcription.
+    Audio,
 }
 
 /// Configuration for an individual model within Bedrock provider.
@@ -35,6 +85,99 @@ pub struct BedrockModelConfig {
     #[serde(default)]
     pub rate_limits: Option<TokenRateLimitsConfig>,
     // No headers field - Bedrock uses SigV4 signing
+    /// Access control policy for this model.
+    #[serde(default)]
+    pub access: Option<ModelAccessConfig>,
+    /// Maximum number of output tokens this model will generate.
+    /// When unset, falls back to the provider's built-in default for the model.
+    #[serde(default)]
+    pub max_tokens: Option<u32>,
+    /// Total context window size (input + output tokens) this model supports.
+    /// When unset, falls back to the provider's built-in default for the model.
+    #[serde(default)]
+    pub context_window: Option<u32>,
+}
+
+/// Per-model access control policy.
+///
+/// Lets operators roll out newly released or restricted models to a subset
+/// of users before general availability, gating on group membership and
+/// caller country in addition to whatever provider-level access applies.
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct ModelAccessConfig {
+    /// Groups allowed to use this model. Empty means no group restriction.
+    pub allowed_groups: Vec<String>,
+    /// ISO country codes allowed access to this model. Empty means no allow-list restriction.
+    pub allowed_countries: Vec<String>,
+    /// ISO country codes denied access to this model.
+    pub denied_countries: Vec<String>,
+    /// Whether this model is in closed beta (only explicitly allowed groups may use it).
+    pub closed_beta: bool,
+}
+
+/// Provider-level geographic access control policy.
+///
+/// Lets operators restrict an entire provider to specific regions. Models can narrow
+/// this further (or override it outright) with their own [`ModelAccessConfig`].
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProviderAccessConfig {
+    /// ISO country codes allowed access to this provider. Empty means no allow-list restriction.
+    pub allowed_countries: Vec<String>,
+    /// ISO country codes denied access to this provider.
+    pub denied_countries: Vec<String>,
+}
+
+/// Resolve whether a caller from `country` may use a model, given the provider's and the
+/// model's country restrictions.
+///
+/// A model that configures any country rule takes full precedence over the provider's rule
+/// for that request; otherwise the provider's rule applies. Within whichever level applies,
+/// an explicit denial always wins over an allow-list entry.
+pub fn is_country_allowed(
+    provider_access: Option<&ProviderAccessConfig>,
+    model_access: Option<&ModelAccessConfig>,
+    country: &str,
+) -> bool {
+    let model_rules =
+        model_access.filter(|access| !access.allowed_countries.is_empty() || !access.denied_countries.is_empty());
+
+    let (allowed, denied): (&[String], &[String]) = if let Some(access) = model_rules {
+        (&access.allowed_countries, &access.denied_countries)
+    } else if let Some(access) = provider_access {
+        (&access.allowed_countries, &access.denied_countries)
+    } else {
+        return true;
+    };
+
+    if denied.iter().any(|c| c.eq_ignore_ascii_case(country)) {
+        return false;
+    }
+
+    allowed.is_empty() || allowed.iter().any(|c| c.eq_ignore_ascii_case(country))
+}
+
+/// Resolve whether a caller in `group` may use a model, given the model's
+/// [`ModelAccessConfig`].
+///
+/// A model with `closed_beta` set, or one that configures an explicit
+/// `allowed_groups` list, is only usable by callers in one of those groups;
+/// a caller with no group is denied access to such a model. A model with
+/// neither set has no group restriction.
+pub fn is_group_allowed(model_access: Option<&ModelAccessConfig>, group: Option<&str>) -> bool {
+    let Some(access) = model_access else {
+        return true;
+    };
+
+    if !access.closed_beta && access.allowed_groups.is_empty() {
+        return true;
+    }
+
+    match group {
+        Some(group) => access.allowed_groups.iter().any(|g| g == group),
+        None => false,
+    }
 }
 
 /// Unified model configuration that can be either API or Bedrock.
@@ -70,12 +213,89 @@ impl ModelConfig {
             Self::Bedrock(_) => &[], // Bedrock doesn't support headers
         }
     }
+
+    /// Whether this model supports fill-in-the-middle (FIM) completion.
+    pub fn fim(&self) -> bool {
+        match self {
+            Self::Api(config) => config.fim,
+            Self::Bedrock(_) => false, // Bedrock doesn't support FIM
+        }
+    }
+
+    /// Get the access control policy for this model, if any.
+    pub fn access(&self) -> Option<&ModelAccessConfig> {
+        match self {
+            Self::Api(config) => config.access.as_ref(),
+            Self::Bedrock(config) => config.access.as_ref(),
+        }
+    }
+
+    /// Maximum number of output tokens this model will generate, if overridden.
+    ///
+    /// Returns `None` when the operator hasn't set an explicit limit, in which
+    /// case callers should fall back to the provider's built-in default.
+    pub fn max_tokens(&self) -> Option<u32> {
+        match self {
+            Self::Api(config) => config.max_tokens,
+            Self::Bedrock(config) => config.max_tokens,
+        }
+    }
+
+    /// Total context window size (input + output tokens) this model supports, if overridden.
+    ///
+    /// Returns `None` when the operator hasn't set an explicit limit, in which
+    /// case callers should fall back to the provider's built-in default.
+    pub fn context_window(&self) -> Option<u32> {
+        match self {
+            Self::Api(config) => config.context_window,
+            Self::Bedrock(config) => config.context_window,
+        }
+    }
+
+    /// Azure OpenAI deployment name this model routes to, if configured.
+    pub fn deployment_id(&self) -> Option<&str> {
+        match self {
+            Self::Api(config) => config.deployment_id.as_deref(),
+            Self::Bedrock(_) => None, // Bedrock doesn't use Azure deployments
+        }
+    }
+
+    /// Content modality this model produces, used to select token vs. request rate limiting.
+    pub fn modality(&self) -> Modality {
+        match self {
+            Self::Api(config) => config.modality,
+            Self::Bedrock(_) => Modality::Text, // Bedrock only serves text models today
+        }
+    }
 }
 
+/// Current schema version for [`LlmConfig`].
+const LLM_CONFIG_VERSION: u32 = 1;
+
 /// LLM configuration for AI model integration.
-#[derive(Debug, Clone, Deserialize)]
-#[serde(default, deny_unknown_fields)]
+///
+/// # Schema versioning
+///
+/// `version` controls how the provider/model schema is parsed so the crate can
+/// evolve the format without breaking existing deployments:
+///
+/// - `version = 1` (default): models are declared nested under each provider,
+///   e.g. `[providers.openai.models.gpt-4]`. This is the format in use today.
+/// - `version = 2`: models are declared once, flattened, under top-level
+///   `[[available_models]]` entries that reference their provider by name
+///   (`provider = "openai"`). This is meant for large catalogs where repeating
+///   the provider table per model is unwieldy. Both forms normalize into the
+///   same per-provider `BTreeMap<String, ModelConfig>` once loaded, so the rest
+///   of the crate never needs to know which version a config was written in.
+///
+/// Operators upgrading should keep `version = 1` (or omit it) until they
+/// intentionally migrate to the flattened form; there is no automatic
+/// conversion between the two on disk.
+#[derive(Debug, Clone)]
 pub struct LlmConfig {
+    /// Schema version for the provider/model configuration.
+    version: u32,
+
     /// Whether the LLM functionality is enabled.
     enabled: bool,
 
@@ -84,18 +304,41 @@ pub struct LlmConfig {
 
     /// Map of LLM provider configurations.
     pub providers: BTreeMap<String, LlmProviderConfig>,
+
+    /// Bearer-token authentication for the `/llm` endpoints.
+    ///
+    /// When set, Nexus acts as a gated LLM backend: callers must present a
+    /// `Bearer` JWT signed with `auth.secret` and satisfying `auth.required_claims`
+    /// before any request reaches a provider. This is meant for deployments that
+    /// run Nexus as a standalone LLM proxy behind a front-end service which mints
+    /// the tokens, separate from the per-provider `api_key`/`forward_token` story.
+    pub auth: Option<LlmAuthConfig>,
+
+    /// HTTP header carrying the caller's ISO country code, used to evaluate
+    /// [`ProviderAccessConfig`]/[`ModelAccessConfig`] country restrictions.
+    ///
+    /// Set this to whatever header an upstream proxy or CDN populates with geo
+    /// information (e.g. `X-Country-Code`, `CloudFront-Viewer-Country`).
+    pub country_header: Cow<'static, str>,
 }
 
 impl Default for LlmConfig {
     fn default() -> Self {
         Self {
+            version: LLM_CONFIG_VERSION,
             enabled: true,
             path: Cow::Borrowed("/llm"),
             providers: BTreeMap::new(),
+            auth: None,
+            country_header: Cow::Borrowed(DEFAULT_COUNTRY_HEADER),
         }
     }
 }
 
+/// Default HTTP header consulted for the caller's country, when geographic
+/// access control is configured but no explicit header is set.
+const DEFAULT_COUNTRY_HEADER: &str = "X-Country-Code";
+
 impl LlmConfig {
     /// Whether the LLM functionality is enabled.
     pub fn enabled(&self) -> bool {
@@ -106,6 +349,142 @@ impl LlmConfig {
     pub fn has_providers(&self) -> bool {
         !self.providers.is_empty()
     }
+
+    /// The schema version this configuration was parsed as.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+}
+
+/// Bearer-token authentication for a standalone LLM proxy deployment.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LlmAuthConfig {
+    /// Shared secret used to sign and verify the HMAC-signed JWT access tokens.
+    /// Accepts a literal value, `{ env = "VAR_NAME" }`, or `{ file = "/path" }`.
+    #[serde(deserialize_with = "crate::secret::deserialize_secret")]
+    pub secret: SecretString,
+    /// How long a minted token remains valid.
+    #[serde(default = "default_token_ttl", deserialize_with = "deserialize_duration")]
+    pub token_ttl: Duration,
+    /// Claims that must be present (and match) on every token before a request
+    /// is allowed to reach a provider, e.g. `{ "aud" = "nexus-llm" }`.
+    #[serde(default)]
+    pub required_claims: BTreeMap<String, String>,
+}
+
+fn default_token_ttl() -> Duration {
+    Duration::from_secs(3600)
+}
+
+/// A single model entry in the version-2 flattened `available_models` format.
+///
+/// Carries a `provider` reference so the flat list can be merged back into
+/// the corresponding entry in `providers` during normalization.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FlatModelEntry {
+    /// Name of the provider (key into `providers`) this model belongs to.
+    provider: String,
+    /// Model identifier exposed to clients.
+    id: String,
+    /// The model's own configuration, same shape as a nested `models.<id>` table.
+    #[serde(flatten)]
+    config: ApiModelConfig,
+}
+
+/// Shadow struct mirroring the on-disk shape of [`LlmConfig`], used so we can
+/// branch on `version` before deciding how to assemble the final `providers` map.
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct RawLlmConfig {
+    version: u32,
+    enabled: bool,
+    path: Cow<'static, str>,
+    providers: BTreeMap<String, LlmProviderConfig>,
+    available_models: Vec<FlatModelEntry>,
+    auth: Option<LlmAuthConfig>,
+    country_header: Cow<'static, str>,
+}
+
+impl Default for RawLlmConfig {
+    fn default() -> Self {
+        Self {
+            version: LLM_CONFIG_VERSION,
+            enabled: true,
+            path: Cow::Borrowed("/llm"),
+            providers: BTreeMap::new(),
+            available_models: Vec::new(),
+            auth: None,
+            country_header: Cow::Borrowed(DEFAULT_COUNTRY_HEADER),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for LlmConfig {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        let raw = RawLlmConfig::deserialize(deserializer)?;
+        let mut providers = raw.providers;
+
+        match raw.version {
+            1 => {
+                if !raw.available_models.is_empty() {
+                    return Err(Error::custom(
+                        "`available_models` is a version-2 config field; set `version = 2` to use it",
+                    ));
+                }
+            }
+            2 => {
+                for entry in raw.available_models {
+                    let provider = providers.get_mut(&entry.provider).ok_or_else(|| {
+                        Error::custom(format!(
+                            "available_models entry '{}' references unknown provider '{}'",
+                            entry.id, entry.provider
+                        ))
+                    })?;
+
+                    provider.insert_model(entry.id, entry.config).map_err(Error::custom)?;
+                }
+            }
+            other => {
+                return Err(Error::custom(format!(
+                    "unsupported llm config schema version {other}; supported versions are 1 and 2"
+                )));
+            }
+        }
+
+        for (name, provider) in &providers {
+            if provider.models().is_empty() {
+                return Err(Error::custom(format!(
+                    "At least one model must be configured for provider '{name}'"
+                )));
+            }
+
+            if matches!(provider, LlmProviderConfig::Azure(_)) {
+                for (model_id, model) in provider.models() {
+                    if model.deployment_id().is_none() {
+                        return Err(Error::custom(format!(
+                            "Azure model '{model_id}' on provider '{name}' must specify deployment_id"
+                        )));
+                    }
+                }
+            }
+        }
+
+        Ok(Self {
+            version: raw.version,
+            enabled: raw.enabled,
+            path: raw.path,
+            providers,
+            auth: raw.auth,
+            country_header: raw.country_header,
+        })
+    }
 }
 
 /// Provider type enumeration.
@@ -120,13 +499,23 @@ pub enum ProviderType {
     Google,
     /// AWS Bedrock provider.
     Bedrock,
+    /// Mistral provider.
+    Mistral,
+    /// Ollama provider (local inference server).
+    Ollama,
+    /// Azure OpenAI provider.
+    Azure,
+    /// Generic OpenAI-compatible provider (self-hosted or third-party backends
+    /// speaking the OpenAI wire format, e.g. vLLM, llama.cpp servers).
+    OpenaiCompatible,
 }
 
 /// Configuration specific to API-based providers.
 #[derive(Debug, Clone, Deserialize)]
 pub struct ApiProviderConfig {
-    /// API key for authentication.
-    #[serde(default)]
+    /// API key for authentication. Accepts a literal value, `{ env = "VAR_NAME" }`,
+    /// or `{ file = "/path" }`.
+    #[serde(default, deserialize_with = "crate::secret::deserialize_optional_secret")]
     pub api_key: Option<SecretString>,
 
     /// Custom base URL for the provider API.
@@ -149,21 +538,180 @@ pub struct ApiProviderConfig {
     /// Header transformation rules for this provider.
     #[serde(default)]
     pub headers: Vec<HeaderRule>,
+
+    /// Geographic access control policy for this provider.
+    #[serde(default)]
+    pub access: Option<ProviderAccessConfig>,
+
+    /// Client TLS configuration, for presenting a client certificate (mutual TLS) or
+    /// pinning a custom CA when the provider sits behind an mTLS-terminating gateway.
+    #[serde(default)]
+    pub tls: Option<TlsClientConfig>,
+}
+
+/// Configuration specific to Ollama.
+///
+/// Ollama typically runs on the same host or local network, so it needs
+/// neither an API key nor an explicit `base_url` to get started.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OllamaProviderConfig {
+    /// API key for authentication. Most Ollama deployments don't require one.
+    /// Accepts a literal value, `{ env = "VAR_NAME" }`, or `{ file = "/path" }`.
+    #[serde(default, deserialize_with = "crate::secret::deserialize_optional_secret")]
+    pub api_key: Option<SecretString>,
+
+    /// Base URL for the Ollama server.
+    #[serde(default = "default_ollama_base_url")]
+    pub base_url: String,
+
+    /// Enable token forwarding from user requests.
+    #[serde(default)]
+    pub forward_token: bool,
+
+    /// Explicitly configured models for this provider.
+    /// Phase 3: At least one model must be configured.
+    #[serde(deserialize_with = "deserialize_non_empty_api_models_with_default")]
+    pub models: BTreeMap<String, ApiModelConfig>,
+
+    /// Provider-level rate limits.
+    #[serde(default)]
+    pub rate_limits: Option<TokenRateLimitsConfig>,
+
+    /// Header transformation rules for this provider.
+    #[serde(default)]
+    pub headers: Vec<HeaderRule>,
+}
+
+fn default_ollama_base_url() -> String {
+    "http://localhost:11434/v1".to_string()
+}
+
+impl From<OllamaProviderConfig> for ApiProviderConfig {
+    /// Ollama exposes an OpenAI-compatible `/v1` endpoint, so it can be driven
+    /// through the same client machinery as the other API-based providers.
+    fn from(config: OllamaProviderConfig) -> Self {
+        Self {
+            api_key: config.api_key,
+            base_url: Some(config.base_url),
+            forward_token: config.forward_token,
+            models: config.models,
+            rate_limits: config.rate_limits,
+            headers: config.headers,
+            access: None,
+            tls: None,
+        }
+    }
+}
+
+/// Configuration for a generic OpenAI-compatible provider.
+///
+/// Targets self-hosted or third-party backends that speak the OpenAI
+/// `/chat/completions` wire format but aren't one of the named providers above
+/// (e.g. vLLM, llama.cpp servers, or other OpenAI-shaped gateways). Unlike
+/// [`ApiProviderConfig`], `base_url` is required - there's no sensible default
+/// for an arbitrary self-hosted backend - and `models` is the authoritative
+/// allow-list: only the configured keys may be requested.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OpenAiCompatibleProviderConfig {
+    /// API key for authentication, if the backend requires one. Accepts a literal value,
+    /// `{ env = "VAR_NAME" }`, or `{ file = "/path" }`.
+    #[serde(default, deserialize_with = "crate::secret::deserialize_optional_secret")]
+    pub api_key: Option<SecretString>,
+
+    /// Base URL of the OpenAI-compatible backend, e.g. `http://localhost:8000/v1`.
+    pub base_url: String,
+
+    /// Enable token forwarding from user requests.
+    #[serde(default)]
+    pub forward_token: bool,
+
+    /// Explicitly configured models for this provider. Only these model names
+    /// may be requested; any other model name is rejected as not found.
+    #[serde(deserialize_with = "deserialize_non_empty_api_models_with_default")]
+    pub models: BTreeMap<String, ApiModelConfig>,
+
+    /// Provider-level rate limits.
+    #[serde(default)]
+    pub rate_limits: Option<TokenRateLimitsConfig>,
+
+    /// Header transformation rules for this provider.
+    #[serde(default)]
+    pub headers: Vec<HeaderRule>,
+
+    /// Client TLS configuration, for presenting a client certificate (mutual TLS) or
+    /// pinning a custom CA when the backend sits behind an mTLS-terminating gateway.
+    #[serde(default)]
+    pub tls: Option<TlsClientConfig>,
+}
+
+impl From<OpenAiCompatibleProviderConfig> for ApiProviderConfig {
+    fn from(config: OpenAiCompatibleProviderConfig) -> Self {
+        Self {
+            api_key: config.api_key,
+            base_url: Some(config.base_url),
+            forward_token: config.forward_token,
+            models: config.models,
+            rate_limits: config.rate_limits,
+            headers: config.headers,
+            access: None,
+            tls: config.tls,
+        }
+    }
+}
+
+/// Configuration specific to Azure OpenAI.
+///
+/// Azure routes requests to `https://{resource_name}.openai.azure.com/openai/deployments/{deployment_id}/chat/completions?api-version={api_version}`
+/// and authenticates with an `api-key` header rather than `Authorization: Bearer`. Each model
+/// must set [`ApiModelConfig::deployment_id`] to the Azure deployment it routes to; `rename`
+/// still controls the name exposed to clients, independently of the deployment.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AzureProviderConfig {
+    /// Azure OpenAI resource name, i.e. the `{resource_name}` in `https://{resource_name}.openai.azure.com`.
+    pub resource_name: String,
+
+    /// API key for authentication, sent as the `api-key` header. Accepts a literal value,
+    /// `{ env = "VAR_NAME" }`, or `{ file = "/path" }`.
+    #[serde(deserialize_with = "crate::secret::deserialize_secret")]
+    pub api_key: SecretString,
+
+    /// Azure OpenAI REST API version, e.g. `2024-02-01`.
+    #[serde(default = "default_azure_api_version")]
+    pub api_version: String,
+
+    /// Explicitly configured models for this provider. Every model must set `deployment_id`.
+    #[serde(deserialize_with = "deserialize_non_empty_api_models_with_default")]
+    pub models: BTreeMap<String, ApiModelConfig>,
+
+    /// Provider-level rate limits.
+    #[serde(default)]
+    pub rate_limits: Option<TokenRateLimitsConfig>,
+
+    /// Header transformation rules for this provider.
+    #[serde(default)]
+    pub headers: Vec<HeaderRule>,
+}
+
+fn default_azure_api_version() -> String {
+    "2024-02-01".to_string()
 }
 
 /// Configuration specific to AWS Bedrock.
 #[derive(Debug, Clone, Deserialize)]
 pub struct BedrockProviderConfig {
     /// AWS Access Key ID (optional - uses credential chain if not provided).
-    #[serde(default)]
+    /// Accepts a literal value, `{ env = "VAR_NAME" }`, or `{ file = "/path" }`.
+    #[serde(default, deserialize_with = "crate::secret::deserialize_optional_secret")]
     pub access_key_id: Option<SecretString>,
 
     /// AWS Secret Access Key (required if access_key_id is provided).
-    #[serde(default)]
+    /// Accepts a literal value, `{ env = "VAR_NAME" }`, or `{ file = "/path" }`.
+    #[serde(default, deserialize_with = "crate::secret::deserialize_optional_secret")]
     pub secret_access_key: Option<SecretString>,
 
     /// AWS Session Token (optional - for temporary credentials).
-    #[serde(default)]
+    /// Accepts a literal value, `{ env = "VAR_NAME" }`, or `{ file = "/path" }`.
+    #[serde(default, deserialize_with = "crate::secret::deserialize_optional_secret")]
     pub session_token: Option<SecretString>,
 
     /// AWS Profile name (optional - uses default profile if not specified).
@@ -198,6 +746,18 @@ pub enum LlmProviderConfig {
 
     /// AWS Bedrock provider configuration.
     Bedrock(BedrockProviderConfig),
+
+    /// Mistral provider configuration.
+    Mistral(ApiProviderConfig),
+
+    /// Ollama provider configuration.
+    Ollama(OllamaProviderConfig),
+
+    /// Azure OpenAI provider configuration.
+    Azure(AzureProviderConfig),
+
+    /// Generic OpenAI-compatible provider configuration.
+    OpenaiCompatible(OpenAiCompatibleProviderConfig),
 }
 
 impl LlmProviderConfig {
@@ -208,6 +768,10 @@ impl LlmProviderConfig {
             Self::Anthropic(_) => ProviderType::Anthropic,
             Self::Google(_) => ProviderType::Google,
             Self::Bedrock(_) => ProviderType::Bedrock,
+            Self::Mistral(_) => ProviderType::Mistral,
+            Self::Ollama(_) => ProviderType::Ollama,
+            Self::Azure(_) => ProviderType::Azure,
+            Self::OpenaiCompatible(_) => ProviderType::OpenaiCompatible,
         }
     }
 
@@ -218,6 +782,10 @@ impl LlmProviderConfig {
             Self::Anthropic(config) => config.api_key.as_ref(),
             Self::Google(config) => config.api_key.as_ref(),
             Self::Bedrock(_) => None, // Bedrock doesn't use API keys
+            Self::Mistral(config) => config.api_key.as_ref(),
+            Self::Ollama(config) => config.api_key.as_ref(),
+            Self::Azure(config) => Some(&config.api_key),
+            Self::OpenaiCompatible(config) => config.api_key.as_ref(),
         }
     }
 
@@ -228,6 +796,10 @@ impl LlmProviderConfig {
             Self::Anthropic(config) => config.base_url.as_deref(),
             Self::Google(config) => config.base_url.as_deref(),
             Self::Bedrock(config) => config.base_url.as_deref(),
+            Self::Mistral(config) => config.base_url.as_deref(),
+            Self::Ollama(config) => Some(config.base_url.as_str()),
+            Self::Azure(_) => None, // Azure's URL is built per-model from resource_name/deployment_id
+            Self::OpenaiCompatible(config) => Some(config.base_url.as_str()),
         }
     }
 
@@ -238,6 +810,10 @@ impl LlmProviderConfig {
             Self::Anthropic(config) => config.forward_token,
             Self::Google(config) => config.forward_token,
             Self::Bedrock(_) => false, // Bedrock doesn't support token forwarding
+            Self::Mistral(config) => config.forward_token,
+            Self::Ollama(config) => config.forward_token,
+            Self::Azure(_) => false, // Azure always authenticates with the static api_key
+            Self::OpenaiCompatible(config) => config.forward_token,
         }
     }
 
@@ -264,6 +840,26 @@ impl LlmProviderConfig {
                 .iter()
                 .map(|(k, v)| (k.clone(), ModelConfig::Bedrock(v.clone())))
                 .collect(),
+            Self::Mistral(config) => config
+                .models
+                .iter()
+                .map(|(k, v)| (k.clone(), ModelConfig::Api(v.clone())))
+                .collect(),
+            Self::Ollama(config) => config
+                .models
+                .iter()
+                .map(|(k, v)| (k.clone(), ModelConfig::Api(v.clone())))
+                .collect(),
+            Self::Azure(config) => config
+                .models
+                .iter()
+                .map(|(k, v)| (k.clone(), ModelConfig::Api(v.clone())))
+                .collect(),
+            Self::OpenaiCompatible(config) => config
+                .models
+                .iter()
+                .map(|(k, v)| (k.clone(), ModelConfig::Api(v.clone())))
+                .collect(),
         }
     }
 
@@ -274,56 +870,78 @@ impl LlmProviderConfig {
             Self::Anthropic(config) => config.rate_limits.as_ref(),
             Self::Google(config) => config.rate_limits.as_ref(),
             Self::Bedrock(_) => None, // Bedrock doesn't support rate limits yet
+            Self::Mistral(config) => config.rate_limits.as_ref(),
+            Self::Ollama(config) => config.rate_limits.as_ref(),
+            Self::Azure(config) => config.rate_limits.as_ref(),
+            Self::OpenaiCompatible(config) => config.rate_limits.as_ref(),
+        }
+    }
+
+    /// Get the geographic access control policy for this provider, if any.
+    pub fn access(&self) -> Option<&ProviderAccessConfig> {
+        match self {
+            Self::Openai(config) => config.access.as_ref(),
+            Self::Anthropic(config) => config.access.as_ref(),
+            Self::Google(config) => config.access.as_ref(),
+            Self::Bedrock(_) => None, // Bedrock doesn't support geo access control yet
+            Self::Mistral(config) => config.access.as_ref(),
+            Self::Ollama(_) => None, // Ollama has no ApiProviderConfig-style access field
+            Self::Azure(_) => None, // Azure has no provider-level access field
+            Self::OpenaiCompatible(_) => None, // Generic backends have no provider-level access field
         }
     }
+
+    /// Insert a model into this provider's model map.
+    ///
+    /// Used to merge the version-2 flattened `available_models` format back
+    /// into the per-provider representation. Fails for providers whose model
+    /// configuration isn't shaped like [`ApiModelConfig`] (currently Bedrock),
+    /// since the flat format doesn't carry Bedrock-specific fields.
+    fn insert_model(&mut self, id: String, config: ApiModelConfig) -> Result<(), String> {
+        match self {
+            Self::Openai(provider) => provider.models.insert(id, config),
+            Self::Anthropic(provider) => provider.models.insert(id, config),
+            Self::Google(provider) => provider.models.insert(id, config),
+            Self::Mistral(provider) => provider.models.insert(id, config),
+            Self::Ollama(provider) => provider.models.insert(id, config),
+            Self::Azure(provider) => provider.models.insert(id, config),
+            Self::OpenaiCompatible(provider) => provider.models.insert(id, config),
+            Self::Bedrock(_) => {
+                return Err(format!(
+                    "available_models entry '{id}' targets a Bedrock provider, which isn't supported by the flattened schema"
+                ));
+            }
+        };
+
+        Ok(())
+    }
 }
 
-/// Custom deserializer for API models that ensures at least one model is configured.
-/// This handles both missing field (uses default) and empty map cases.
+/// Custom deserializer for API models that defaults a missing field to an empty map.
+///
+/// The "at least one model" invariant is enforced once, after schema normalization,
+/// by [`LlmConfig`]'s own `Deserialize` impl - a version-2 config legitimately parses
+/// this field as empty because its models arrive later via `available_models`.
 fn deserialize_non_empty_api_models_with_default<'de, D>(
     deserializer: D,
 ) -> Result<BTreeMap<String, ApiModelConfig>, D::Error>
 where
     D: Deserializer<'de>,
 {
-    use serde::de::Error;
-
-    // First deserialize as Option to handle missing field
-    let models_opt = Option::<BTreeMap<String, ApiModelConfig>>::deserialize(deserializer)?;
-
-    // Get the models map, using empty map if field was missing
-    let models = models_opt.unwrap_or_default();
-
-    // Now validate that we have at least one model
-    if models.is_empty() {
-        Err(Error::custom("At least one model must be configured for each provider"))
-    } else {
-        Ok(models)
-    }
+    Ok(Option::<BTreeMap<String, ApiModelConfig>>::deserialize(deserializer)?.unwrap_or_default())
 }
 
-/// Custom deserializer for Bedrock models that ensures at least one model is configured.
-/// This handles both missing field (uses default) and empty map cases.
+/// Custom deserializer for Bedrock models that defaults a missing field to an empty map.
+///
+/// See [`deserialize_non_empty_api_models_with_default`] for why the actual
+/// non-empty check happens later, in [`LlmConfig`]'s `Deserialize` impl.
 fn deserialize_non_empty_bedrock_models_with_default<'de, D>(
     deserializer: D,
 ) -> Result<BTreeMap<String, BedrockModelConfig>, D::Error>
 where
     D: Deserializer<'de>,
 {
-    use serde::de::Error;
-
-    // First deserialize as Option to handle missing field
-    let models_opt = Option::<BTreeMap<String, BedrockModelConfig>>::deserialize(deserializer)?;
-
-    // Get the models map, using empty map if field was missing
-    let models = models_opt.unwrap_or_default();
-
-    // Now validate that we have at least one model
-    if models.is_empty() {
-        Err(Error::custom("At least one model must be configured for each provider"))
-    } else {
-        Ok(models)
-    }
+    Ok(Option::<BTreeMap<String, BedrockModelConfig>>::deserialize(deserializer)?.unwrap_or_default())
 }
 
 #[cfg(test)]
@@ -338,9 +956,12 @@ mod tests {
 
         assert_debug_snapshot!(&config, @r#"
         LlmConfig {
+            version: 1,
             enabled: true,
             path: "/llm",
             providers: {},
+            auth: None,
+            country_header: "X-Country-Code",
         }
         "#);
     }
@@ -364,6 +985,7 @@ mod tests {
 
         assert_debug_snapshot!(&config, @r#"
         LlmConfig {
+            version: 1,
             enabled: true,
             path: "/llm",
             providers: {
@@ -379,18 +1001,34 @@ mod tests {
                                 rename: None,
                                 rate_limits: None,
                                 headers: [],
+                                fim: false,
+                                access: None,
+                                max_tokens: None,
+                                context_window: None,
+                                deployment_id: None,
+                                modality: Text,
                             },
                             "gpt-4": ApiModelConfig {
                                 rename: None,
                                 rate_limits: None,
                                 headers: [],
+                                fim: false,
+                                access: None,
+                                max_tokens: None,
+                                context_window: None,
+                                deployment_id: None,
+                                modality: Text,
                             },
                         },
                         rate_limits: None,
                         headers: [],
+                        access: None,
+                        tls: None,
                     },
                 ),
             },
+            auth: None,
+            country_header: "X-Country-Code",
         }
         "#);
     }
@@ -414,6 +1052,7 @@ mod tests {
 
         assert_debug_snapshot!(&config, @r#"
         LlmConfig {
+            version: 1,
             enabled: true,
             path: "/llm",
             providers: {
@@ -429,18 +1068,34 @@ mod tests {
                                 rename: None,
                                 rate_limits: None,
                                 headers: [],
+                                fim: false,
+                                access: None,
+                                max_tokens: None,
+                                context_window: None,
+                                deployment_id: None,
+                                modality: Text,
                             },
                             "claude-3-sonnet": ApiModelConfig {
                                 rename: None,
                                 rate_limits: None,
                                 headers: [],
+                                fim: false,
+                                access: None,
+                                max_tokens: None,
+                                context_window: None,
+                                deployment_id: None,
+                                modality: Text,
                             },
                         },
                         rate_limits: None,
                         headers: [],
+                        access: None,
+                        tls: None,
                     },
                 ),
             },
+            auth: None,
+            country_header: "X-Country-Code",
         }
         "#);
     }
@@ -461,6 +1116,7 @@ mod tests {
 
         assert_debug_snapshot!(&config, @r#"
         LlmConfig {
+            version: 1,
             enabled: true,
             path: "/llm",
             providers: {
@@ -476,18 +1132,34 @@ mod tests {
                                 rename: None,
                                 rate_limits: None,
                                 headers: [],
+                                fim: false,
+                                access: None,
+                                max_tokens: None,
+                                context_window: None,
+                                deployment_id: None,
+                                modality: Text,
                             },
                             "gemini-pro-vision": ApiModelConfig {
                                 rename: None,
                                 rate_limits: None,
                                 headers: [],
+                                fim: false,
+                                access: None,
+                                max_tokens: None,
+                                context_window: None,
+                                deployment_id: None,
+                                modality: Text,
                             },
                         },
                         rate_limits: None,
                         headers: [],
+                        access: None,
+                        tls: None,
                     },
                 ),
             },
+            auth: None,
+            country_header: "X-Country-Code",
         }
         "#);
     }
@@ -521,6 +1193,7 @@ mod tests {
 
         assert_debug_snapshot!(&config, @r#"
         LlmConfig {
+            version: 1,
             enabled: true,
             path: "/ai",
             providers: {
@@ -536,10 +1209,18 @@ mod tests {
                                 rename: None,
                                 rate_limits: None,
                                 headers: [],
+                                fim: false,
+                                access: None,
+                                max_tokens: None,
+                                context_window: None,
+                                deployment_id: None,
+                                modality: Text,
                             },
                         },
                         rate_limits: None,
                         headers: [],
+                        access: None,
+                        tls: None,
                     },
                 ),
                 "google": Google(
@@ -554,10 +1235,18 @@ mod tests {
                                 rename: None,
                                 rate_limits: None,
                                 headers: [],
+                                fim: false,
+                                access: None,
+                                max_tokens: None,
+                                context_window: None,
+                                deployment_id: None,
+                                modality: Text,
                             },
                         },
                         rate_limits: None,
                         headers: [],
+                        access: None,
+                        tls: None,
                     },
                 ),
                 "openai": Openai(
@@ -572,13 +1261,23 @@ mod tests {
                                 rename: None,
                                 rate_limits: None,
                                 headers: [],
+                                fim: false,
+                                access: None,
+                                max_tokens: None,
+                                context_window: None,
+                                deployment_id: None,
+                                modality: Text,
                             },
                         },
                         rate_limits: None,
                         headers: [],
+                        access: None,
+                        tls: None,
                     },
                 ),
             },
+            auth: None,
+            country_header: "X-Country-Code",
         }
         "#);
     }
@@ -593,9 +1292,12 @@ mod tests {
 
         assert_debug_snapshot!(&config, @r#"
         LlmConfig {
+            version: 1,
             enabled: false,
             path: "/llm",
             providers: {},
+            auth: None,
+            country_header: "X-Country-Code",
         }
         "#);
     }
@@ -610,9 +1312,12 @@ mod tests {
 
         assert_debug_snapshot!(&config, @r#"
         LlmConfig {
+            version: 1,
             enabled: true,
             path: "/models",
             providers: {},
+            auth: None,
+            country_header: "X-Country-Code",
         }
         "#);
     }
@@ -646,6 +1351,7 @@ mod tests {
 
         assert_debug_snapshot!(&config, @r#"
         LlmConfig {
+            version: 1,
             enabled: true,
             path: "/llm",
             providers: {
@@ -661,13 +1367,23 @@ mod tests {
                                 rename: None,
                                 rate_limits: None,
                                 headers: [],
+                                fim: false,
+                                access: None,
+                                max_tokens: None,
+                                context_window: None,
+                                deployment_id: None,
+                                modality: Text,
                             },
                         },
                         rate_limits: None,
                         headers: [],
+                        access: None,
+                        tls: None,
                     },
                 ),
             },
+            auth: None,
+            country_header: "X-Country-Code",
         }
         "#);
     }
@@ -690,6 +1406,7 @@ mod tests {
 
         assert_debug_snapshot!(&config, @r#"
         LlmConfig {
+            version: 1,
             enabled: true,
             path: "/llm",
             providers: {
@@ -707,6 +1424,12 @@ mod tests {
                                 ),
                                 rate_limits: None,
                                 headers: [],
+                                fim: false,
+                                access: None,
+                                max_tokens: None,
+                                context_window: None,
+                                deployment_id: None,
+                                modality: Text,
                             },
                             "gpt-4": ApiModelConfig {
                                 rename: Some(
@@ -714,13 +1437,23 @@ mod tests {
                                 ),
                                 rate_limits: None,
                                 headers: [],
+                                fim: false,
+                                access: None,
+                                max_tokens: None,
+                                context_window: None,
+                                deployment_id: None,
+                                modality: Text,
                             },
                         },
                         rate_limits: None,
                         headers: [],
+                        access: None,
+                        tls: None,
                     },
                 ),
             },
+            auth: None,
+            country_header: "X-Country-Code",
         }
         "#);
     }
@@ -743,6 +1476,7 @@ mod tests {
 
         assert_debug_snapshot!(&config, @r#"
         LlmConfig {
+            version: 1,
             enabled: true,
             path: "/llm",
             providers: {
@@ -758,18 +1492,34 @@ mod tests {
                                 rename: None,
                                 rate_limits: None,
                                 headers: [],
+                                fim: false,
+                                access: None,
+                                max_tokens: None,
+                                context_window: None,
+                                deployment_id: None,
+                                modality: Text,
                             },
                             "gpt-4": ApiModelConfig {
                                 rename: None,
                                 rate_limits: None,
                                 headers: [],
+                                fim: false,
+                                access: None,
+                                max_tokens: None,
+                                context_window: None,
+                                deployment_id: None,
+                                modality: Text,
                             },
                         },
                         rate_limits: None,
                         headers: [],
+                        access: None,
+                        tls: None,
                     },
                 ),
             },
+            auth: None,
+            country_header: "X-Country-Code",
         }
         "#);
     }
@@ -799,6 +1549,7 @@ mod tests {
 
         assert_debug_snapshot!(&config, @r#"
         LlmConfig {
+            version: 1,
             enabled: true,
             path: "/llm",
             providers: {
@@ -816,15 +1567,29 @@ mod tests {
                                 ),
                                 rate_limits: None,
                                 headers: [],
+                                fim: false,
+                                access: None,
+                                max_tokens: None,
+                                context_window: None,
+                                deployment_id: None,
+                                modality: Text,
                             },
                             "claude-instant": ApiModelConfig {
                                 rename: None,
                                 rate_limits: None,
                                 headers: [],
+                                fim: false,
+                                access: None,
+                                max_tokens: None,
+                                context_window: None,
+                                deployment_id: None,
+                                modality: Text,
                             },
                         },
                         rate_limits: None,
                         headers: [],
+                        access: None,
+                        tls: None,
                     },
                 ),
                 "openai": Openai(
@@ -841,13 +1606,23 @@ mod tests {
                                 ),
                                 rate_limits: None,
                                 headers: [],
+                                fim: false,
+                                access: None,
+                                max_tokens: None,
+                                context_window: None,
+                                deployment_id: None,
+                                modality: Text,
                             },
                         },
                         rate_limits: None,
                         headers: [],
+                        access: None,
+                        tls: None,
                     },
                 ),
             },
+            auth: None,
+            country_header: "X-Country-Code",
         }
         "#);
     }
@@ -877,15 +1652,24 @@ mod tests {
             TokenRateLimitsConfig {
                 per_user: Some(
                     PerUserRateLimits {
-                        input_token_limit: 100000,
+                        input_token_limit: Some(
+                            100000,
+                        ),
+                        request_limit: None,
                         interval: 60s,
                         groups: {
                             "free": TokenRateLimit {
-                                input_token_limit: 10000,
+                                input_token_limit: Some(
+                                    10000,
+                                ),
+                                request_limit: None,
                                 interval: 60s,
                             },
                             "pro": TokenRateLimit {
-                                input_token_limit: 100000,
+                                input_token_limit: Some(
+                                    100000,
+                                ),
+                                request_limit: None,
                                 interval: 60s,
                             },
                         },
@@ -919,15 +1703,24 @@ mod tests {
             TokenRateLimitsConfig {
                 per_user: Some(
                     PerUserRateLimits {
-                        input_token_limit: 50000,
+                        input_token_limit: Some(
+                            50000,
+                        ),
+                        request_limit: None,
                         interval: 60s,
                         groups: {
                             "free": TokenRateLimit {
-                                input_token_limit: 5000,
+                                input_token_limit: Some(
+                                    5000,
+                                ),
+                                request_limit: None,
                                 interval: 60s,
                             },
                             "pro": TokenRateLimit {
-                                input_token_limit: 50000,
+                                input_token_limit: Some(
+                                    50000,
+                                ),
+                                request_limit: None,
                                 interval: 60s,
                             },
                         },
@@ -939,51 +1732,114 @@ mod tests {
     }
 
     #[test]
-    fn llm_config_with_forward_token_enabled() {
+    fn image_model_request_limit() {
         let config = indoc! {r#"
             [providers.openai]
             type = "openai"
-            api_key = "sk-fallback-key"
-            forward_token = true
-            
-            [providers.openai.models.gpt-4]
+            api_key = "test-key"
 
-            [providers.anthropic]
-            type = "anthropic"
-            forward_token = true
-            # No api_key provided - relies entirely on token forwarding
-            
-            [providers.anthropic.models.claude-3-opus]
+            [providers.openai.models.dall-e-3]
+            type = "image"
 
-            [providers.google]
-            type = "google"
-            api_key = "{{ env.GOOGLE_KEY }}"
-            forward_token = false  # Explicitly disabled
-            
-            [providers.google.models.gemini-pro]
+            [providers.openai.models.dall-e-3.rate_limits.per_user]
+            request_limit = 2
+            interval = "60s"
         "#};
 
         let config: LlmConfig = toml::from_str(config).unwrap();
+        let model = config.providers["openai"].models().get("dall-e-3").unwrap();
 
-        assert_debug_snapshot!(&config, @r#"
-        LlmConfig {
-            enabled: true,
-            path: "/llm",
-            providers: {
-                "anthropic": Anthropic(
-                    ApiProviderConfig {
-                        api_key: None,
-                        base_url: None,
-                        forward_token: true,
-                        models: {
-                            "claude-3-opus": ApiModelConfig {
-                                rename: None,
-                                rate_limits: None,
-                                headers: [],
-                            },
-                        },
+        assert_eq!(model.modality(), Modality::Image);
+
+        assert_debug_snapshot!(model.rate_limits(), @r#"
+        Some(
+            TokenRateLimitsConfig {
+                per_user: Some(
+                    PerUserRateLimits {
+                        input_token_limit: None,
+                        request_limit: Some(
+                            2,
+                        ),
+                        interval: 60s,
+                        groups: {},
+                    },
+                ),
+            },
+        )
+        "#);
+    }
+
+    #[test]
+    fn model_modality_defaults_to_text() {
+        let config = indoc! {r#"
+            [providers.openai]
+            type = "openai"
+            api_key = "test-key"
+
+            [providers.openai.models.gpt-4]
+        "#};
+
+        let config: LlmConfig = toml::from_str(config).unwrap();
+        let model = config.providers["openai"].models().get("gpt-4").unwrap();
+
+        assert_eq!(model.modality(), Modality::Text);
+    }
+
+    #[test]
+    fn llm_config_with_forward_token_enabled() {
+        let config = indoc! {r#"
+            [providers.openai]
+            type = "openai"
+            api_key = "sk-fallback-key"
+            forward_token = true
+            
+            [providers.openai.models.gpt-4]
+
+            [providers.anthropic]
+            type = "anthropic"
+            forward_token = true
+            # No api_key provided - relies entirely on token forwarding
+            
+            [providers.anthropic.models.claude-3-opus]
+
+            [providers.google]
+            type = "google"
+            api_key = "{{ env.GOOGLE_KEY }}"
+            forward_token = false  # Explicitly disabled
+            
+            [providers.google.models.gemini-pro]
+        "#};
+
+        let config: LlmConfig = toml::from_str(config).unwrap();
+
+        assert_debug_snapshot!(&config, @r#"
+        LlmConfig {
+            version: 1,
+            enabled: true,
+            path: "/llm",
+            providers: {
+                "anthropic": Anthropic(
+                    ApiProviderConfig {
+                        api_key: None,
+                        base_url: None,
+                        forward_token: true,
+                        models: {
+                            "claude-3-opus": ApiModelConfig {
+                                rename: None,
+                                rate_limits: None,
+                                headers: [],
+                                fim: false,
+                                access: None,
+                                max_tokens: None,
+                                context_window: None,
+                                deployment_id: None,
+                                modality: Text,
+                            },
+                        },
                         rate_limits: None,
                         headers: [],
+                        access: None,
+                        tls: None,
                     },
                 ),
                 "google": Google(
@@ -998,10 +1854,18 @@ mod tests {
                                 rename: None,
                                 rate_limits: None,
                                 headers: [],
+                                fim: false,
+                                access: None,
+                                max_tokens: None,
+                                context_window: None,
+                                deployment_id: None,
+                                modality: Text,
                             },
                         },
                         rate_limits: None,
                         headers: [],
+                        access: None,
+                        tls: None,
                     },
                 ),
                 "openai": Openai(
@@ -1016,14 +1880,667 @@ mod tests {
                                 rename: None,
                                 rate_limits: None,
                                 headers: [],
+                                fim: false,
+                                access: None,
+                                max_tokens: None,
+                                context_window: None,
+                                deployment_id: None,
+                                modality: Text,
+                            },
+                        },
+                        rate_limits: None,
+                        headers: [],
+                        access: None,
+                        tls: None,
+                    },
+                ),
+            },
+            auth: None,
+            country_header: "X-Country-Code",
+        }
+        "#);
+    }
+
+    #[test]
+    fn llm_config_with_mistral_fim() {
+        let config = indoc! {r#"
+            [providers.mistral]
+            type = "mistral"
+            api_key = "${MISTRAL_API_KEY}"
+
+            [providers.mistral.models.codestral]
+            fim = true
+
+            [providers.mistral.models.mistral-large]
+        "#};
+
+        let config: LlmConfig = toml::from_str(config).unwrap();
+
+        assert_debug_snapshot!(&config, @r#"
+        LlmConfig {
+            version: 1,
+            enabled: true,
+            path: "/llm",
+            providers: {
+                "mistral": Mistral(
+                    ApiProviderConfig {
+                        api_key: Some(
+                            SecretBox<str>([REDACTED]),
+                        ),
+                        base_url: None,
+                        forward_token: false,
+                        models: {
+                            "codestral": ApiModelConfig {
+                                rename: None,
+                                rate_limits: None,
+                                headers: [],
+                                fim: true,
+                                access: None,
+                                max_tokens: None,
+                                context_window: None,
+                                deployment_id: None,
+                                modality: Text,
+                            },
+                            "mistral-large": ApiModelConfig {
+                                rename: None,
+                                rate_limits: None,
+                                headers: [],
+                                fim: false,
+                                access: None,
+                                max_tokens: None,
+                                context_window: None,
+                                deployment_id: None,
+                                modality: Text,
+                            },
+                        },
+                        rate_limits: None,
+                        headers: [],
+                        access: None,
+                        tls: None,
+                    },
+                ),
+            },
+            auth: None,
+            country_header: "X-Country-Code",
+        }
+        "#);
+    }
+
+    #[test]
+    fn llm_config_with_ollama_defaults() {
+        let config = indoc! {r#"
+            [providers.ollama]
+            type = "ollama"
+
+            [providers.ollama.models.llama3]
+        "#};
+
+        let config: LlmConfig = toml::from_str(config).unwrap();
+
+        assert_debug_snapshot!(&config, @r#"
+        LlmConfig {
+            version: 1,
+            enabled: true,
+            path: "/llm",
+            providers: {
+                "ollama": Ollama(
+                    OllamaProviderConfig {
+                        api_key: None,
+                        base_url: "http://localhost:11434/v1",
+                        forward_token: false,
+                        models: {
+                            "llama3": ApiModelConfig {
+                                rename: None,
+                                rate_limits: None,
+                                headers: [],
+                                fim: false,
+                                access: None,
+                                max_tokens: None,
+                                context_window: None,
+                                deployment_id: None,
+                                modality: Text,
+                            },
+                        },
+                        rate_limits: None,
+                        headers: [],
+                    },
+                ),
+            },
+            auth: None,
+            country_header: "X-Country-Code",
+        }
+        "#);
+    }
+
+    #[test]
+    fn llm_config_with_openai_compatible() {
+        let config = indoc! {r#"
+            [providers.vllm]
+            type = "openai-compatible"
+            base_url = "http://localhost:8000/v1"
+
+            [providers.vllm.models.llama-3-70b]
+            rename = "llama-3-70b-instruct"
+        "#};
+
+        let config: LlmConfig = toml::from_str(config).unwrap();
+
+        assert_debug_snapshot!(&config, @r#"
+        LlmConfig {
+            version: 1,
+            enabled: true,
+            path: "/llm",
+            providers: {
+                "vllm": OpenaiCompatible(
+                    OpenAiCompatibleProviderConfig {
+                        api_key: None,
+                        base_url: "http://localhost:8000/v1",
+                        forward_token: false,
+                        models: {
+                            "llama-3-70b": ApiModelConfig {
+                                rename: Some(
+                                    "llama-3-70b-instruct",
+                                ),
+                                rate_limits: None,
+                                headers: [],
+                                fim: false,
+                                access: None,
+                                max_tokens: None,
+                                context_window: None,
+                                deployment_id: None,
+                                modality: Text,
+                            },
+                        },
+                        rate_limits: None,
+                        headers: [],
+                        tls: None,
+                    },
+                ),
+            },
+            auth: None,
+            country_header: "X-Country-Code",
+        }
+        "#);
+    }
+
+    #[test]
+    fn llm_config_openai_compatible_requires_base_url() {
+        let config = indoc! {r#"
+            [providers.vllm]
+            type = "openai-compatible"
+
+            [providers.vllm.models.llama-3-70b]
+        "#};
+
+        let error = toml::from_str::<LlmConfig>(config).unwrap_err();
+
+        assert!(error.to_string().contains("base_url"), "unexpected error: {error}");
+    }
+
+    #[test]
+    fn llm_config_with_flattened_available_models() {
+        let config = indoc! {r#"
+            version = 2
+
+            [providers.openai]
+            type = "openai"
+            api_key = "test-key"
+
+            [[available_models]]
+            provider = "openai"
+            id = "gpt-4"
+
+            [[available_models]]
+            provider = "openai"
+            id = "gpt-4-turbo"
+            rename = "gpt-4-1106-preview"
+        "#};
+
+        let config: LlmConfig = toml::from_str(config).unwrap();
+
+        assert_debug_snapshot!(&config, @r#"
+        LlmConfig {
+            version: 2,
+            enabled: true,
+            path: "/llm",
+            providers: {
+                "openai": Openai(
+                    ApiProviderConfig {
+                        api_key: Some(
+                            SecretBox<str>([REDACTED]),
+                        ),
+                        base_url: None,
+                        forward_token: false,
+                        models: {
+                            "gpt-4": ApiModelConfig {
+                                rename: None,
+                                rate_limits: None,
+                                headers: [],
+                                fim: false,
+                                access: None,
+                                max_tokens: None,
+                                context_window: None,
+                                deployment_id: None,
+                                modality: Text,
+                            },
+                            "gpt-4-turbo": ApiModelConfig {
+                                rename: Some(
+                                    "gpt-4-1106-preview",
+                                ),
+                                rate_limits: None,
+                                headers: [],
+                                fim: false,
+                                access: None,
+                                max_tokens: None,
+                                context_window: None,
+                                deployment_id: None,
+                                modality: Text,
                             },
                         },
                         rate_limits: None,
                         headers: [],
+                        access: None,
+                        tls: None,
                     },
                 ),
             },
+            auth: None,
+            country_header: "X-Country-Code",
+        }
+        "#);
+    }
+
+    #[test]
+    fn llm_config_flattened_model_for_unknown_provider() {
+        let config = indoc! {r#"
+            version = 2
+
+            [providers.openai]
+            type = "openai"
+            api_key = "test-key"
+
+            [[available_models]]
+            provider = "anthropic"
+            id = "claude-3"
+        "#};
+
+        let error = toml::from_str::<LlmConfig>(config).unwrap_err();
+
+        assert!(
+            error.to_string().contains("anthropic"),
+            "error should mention the unknown provider: {error}"
+        );
+    }
+
+    #[test]
+    fn model_access_config() {
+        let config = indoc! {r#"
+            [providers.openai]
+            type = "openai"
+            api_key = "test-key"
+
+            [providers.openai.models.gpt-5]
+            [providers.openai.models.gpt-5.access]
+            allowed_groups = ["internal", "beta-testers"]
+            denied_countries = ["KP", "IR"]
+            closed_beta = true
+        "#};
+
+        let config: LlmConfig = toml::from_str(config).unwrap();
+        let access = config.providers["openai"].models()["gpt-5"].access().cloned();
+
+        assert_debug_snapshot!(access, @r#"
+        Some(
+            ModelAccessConfig {
+                allowed_groups: [
+                    "internal",
+                    "beta-testers",
+                ],
+                allowed_countries: [],
+                denied_countries: [
+                    "KP",
+                    "IR",
+                ],
+                closed_beta: true,
+            },
+        )
+        "#);
+    }
+
+    #[test]
+    fn model_access_config_default_is_none() {
+        let config = indoc! {r#"
+            [providers.openai]
+            type = "openai"
+            api_key = "test-key"
+
+            [providers.openai.models.gpt-4]
+        "#};
+
+        let config: LlmConfig = toml::from_str(config).unwrap();
+
+        assert!(config.providers["openai"].models()["gpt-4"].access().is_none());
+    }
+
+    #[test]
+    fn model_token_metadata() {
+        let config = indoc! {r#"
+            [providers.openai]
+            type = "openai"
+            api_key = "test-key"
+
+            [providers.openai.models.gpt-5]
+            max_tokens = 16384
+            context_window = 400000
+
+            [providers.openai.models.gpt-4]
+        "#};
+
+        let config: LlmConfig = toml::from_str(config).unwrap();
+        let gpt5 = &config.providers["openai"].models()["gpt-5"];
+        let gpt4 = &config.providers["openai"].models()["gpt-4"];
+
+        assert_eq!(gpt5.max_tokens(), Some(16384));
+        assert_eq!(gpt5.context_window(), Some(400000));
+        assert_eq!(gpt4.max_tokens(), None);
+        assert_eq!(gpt4.context_window(), None);
+    }
+
+    #[test]
+    fn llm_config_with_auth() {
+        let config = indoc! {r#"
+            [providers.openai]
+            type = "openai"
+            api_key = "test-key"
+
+            [providers.openai.models.gpt-4]
+
+            [auth]
+            secret = "super-secret-signing-key"
+            token_ttl = "15m"
+            required_claims = { aud = "nexus-llm" }
+        "#};
+
+        let config: LlmConfig = toml::from_str(config).unwrap();
+        let auth = config.auth.as_ref().unwrap();
+
+        assert_debug_snapshot!(auth, @r#"
+        LlmAuthConfig {
+            secret: SecretBox<str>([REDACTED]),
+            token_ttl: 900s,
+            required_claims: {
+                "aud": "nexus-llm",
+            },
         }
         "#);
     }
+
+    #[test]
+    fn llm_config_auth_defaults_to_none() {
+        let config = indoc! {r#"
+            [providers.openai]
+            type = "openai"
+            api_key = "test-key"
+
+            [providers.openai.models.gpt-4]
+        "#};
+
+        let config: LlmConfig = toml::from_str(config).unwrap();
+
+        assert!(config.auth.is_none());
+    }
+
+    #[test]
+    fn llm_config_auth_default_token_ttl() {
+        let config = indoc! {r#"
+            [providers.openai]
+            type = "openai"
+            api_key = "test-key"
+
+            [providers.openai.models.gpt-4]
+
+            [auth]
+            secret = "super-secret-signing-key"
+        "#};
+
+        let config: LlmConfig = toml::from_str(config).unwrap();
+        let auth = config.auth.as_ref().unwrap();
+
+        assert_eq!(auth.token_ttl, Duration::from_secs(3600));
+        assert!(auth.required_claims.is_empty());
+    }
+
+    #[test]
+    fn llm_config_with_azure() {
+        let config = indoc! {r#"
+            [providers.azure]
+            type = "azure"
+            resource_name = "my-company-openai"
+            api_key = "azure-secret-key"
+
+            [providers.azure.models.gpt-4]
+            deployment_id = "my-gpt4-prod"
+        "#};
+
+        let config: LlmConfig = toml::from_str(config).unwrap();
+
+        assert_debug_snapshot!(&config, @r#"
+        LlmConfig {
+            version: 1,
+            enabled: true,
+            path: "/llm",
+            providers: {
+                "azure": Azure(
+                    AzureProviderConfig {
+                        resource_name: "my-company-openai",
+                        api_key: SecretBox<str>([REDACTED]),
+                        api_version: "2024-02-01",
+                        models: {
+                            "gpt-4": ApiModelConfig {
+                                rename: None,
+                                rate_limits: None,
+                                headers: [],
+                                fim: false,
+                                access: None,
+                                max_tokens: None,
+                                context_window: None,
+                                deployment_id: Some(
+                                    "my-gpt4-prod",
+                                ),
+                                modality: Text,
+                            },
+                        },
+                        rate_limits: None,
+                        headers: [],
+                    },
+                ),
+            },
+            auth: None,
+            country_header: "X-Country-Code",
+        }
+        "#);
+    }
+
+    #[test]
+    fn llm_config_azure_model_without_deployment_id_fails() {
+        let config = indoc! {r#"
+            [providers.azure]
+            type = "azure"
+            resource_name = "my-company-openai"
+            api_key = "azure-secret-key"
+
+            [providers.azure.models.gpt-4]
+        "#};
+
+        let error = toml::from_str::<LlmConfig>(config).unwrap_err();
+
+        assert!(
+            error.to_string().contains("deployment_id"),
+            "error should mention deployment_id: {error}"
+        );
+    }
+
+    #[test]
+    fn llm_config_azure_custom_api_version() {
+        let config = indoc! {r#"
+            [providers.azure]
+            type = "azure"
+            resource_name = "my-company-openai"
+            api_key = "azure-secret-key"
+            api_version = "2023-05-15"
+
+            [providers.azure.models.gpt-4]
+            deployment_id = "my-gpt4-prod"
+        "#};
+
+        let config: LlmConfig = toml::from_str(config).unwrap();
+
+        let LlmProviderConfig::Azure(azure) = &config.providers["azure"] else {
+            panic!("expected Azure provider config");
+        };
+
+        assert_eq!(azure.api_version, "2023-05-15");
+    }
+
+    #[test]
+    fn llm_config_country_header_defaults() {
+        let config: LlmConfig = toml::from_str("").unwrap();
+
+        assert_eq!(config.country_header, "X-Country-Code");
+    }
+
+    #[test]
+    fn llm_config_custom_country_header() {
+        let config = indoc! {r#"
+            country_header = "CloudFront-Viewer-Country"
+        "#};
+
+        let config: LlmConfig = toml::from_str(config).unwrap();
+
+        assert_eq!(config.country_header, "CloudFront-Viewer-Country");
+    }
+
+    #[test]
+    fn provider_and_model_geo_access() {
+        let config = indoc! {r#"
+            [providers.openai]
+            type = "openai"
+            api_key = "test-key"
+
+            [providers.openai.access]
+            denied_countries = ["CN"]
+
+            [providers.openai.models.gpt-4]
+
+            [providers.openai.models.gpt-4-preview]
+            access.allowed_countries = ["US", "CA"]
+        "#};
+
+        let config: LlmConfig = toml::from_str(config).unwrap();
+
+        let LlmProviderConfig::Openai(openai) = &config.providers["openai"] else {
+            panic!("expected openai provider config");
+        };
+
+        assert_eq!(openai.access.as_ref().unwrap().denied_countries, vec!["CN"]);
+
+        let preview_access = openai.models["gpt-4-preview"].access.as_ref().unwrap();
+        assert_eq!(preview_access.allowed_countries, vec!["US", "CA"]);
+    }
+
+    #[test]
+    fn country_allowed_no_restrictions() {
+        assert!(is_country_allowed(None, None, "US"));
+    }
+
+    #[test]
+    fn country_allowed_provider_denied_country() {
+        let provider = ProviderAccessConfig {
+            allowed_countries: vec![],
+            denied_countries: vec!["CN".to_string()],
+        };
+
+        assert!(!is_country_allowed(Some(&provider), None, "CN"));
+        assert!(is_country_allowed(Some(&provider), None, "US"));
+    }
+
+    #[test]
+    fn country_allowed_provider_allow_list() {
+        let provider = ProviderAccessConfig {
+            allowed_countries: vec!["US".to_string(), "CA".to_string()],
+            denied_countries: vec![],
+        };
+
+        assert!(is_country_allowed(Some(&provider), None, "US"));
+        assert!(!is_country_allowed(Some(&provider), None, "DE"));
+    }
+
+    #[test]
+    fn country_allowed_model_overrides_provider() {
+        let provider = ProviderAccessConfig {
+            allowed_countries: vec![],
+            denied_countries: vec!["US".to_string()],
+        };
+
+        let model = ModelAccessConfig {
+            allowed_countries: vec!["US".to_string()],
+            ..Default::default()
+        };
+
+        // The model's own rule takes full precedence over the provider's denial.
+        assert!(is_country_allowed(Some(&provider), Some(&model), "US"));
+    }
+
+    #[test]
+    fn country_allowed_deny_takes_precedence_over_allow() {
+        let model = ModelAccessConfig {
+            allowed_countries: vec!["US".to_string()],
+            denied_countries: vec!["US".to_string()],
+            ..Default::default()
+        };
+
+        assert!(!is_country_allowed(None, Some(&model), "US"));
+    }
+
+    #[test]
+    fn group_allowed_no_restrictions() {
+        assert!(is_group_allowed(None, None));
+        assert!(is_group_allowed(None, Some("beta-testers")));
+    }
+
+    #[test]
+    fn group_allowed_allow_list() {
+        let model = ModelAccessConfig {
+            allowed_groups: vec!["beta-testers".to_string()],
+            ..Default::default()
+        };
+
+        assert!(is_group_allowed(Some(&model), Some("beta-testers")));
+        assert!(!is_group_allowed(Some(&model), Some("everyone-else")));
+        assert!(!is_group_allowed(Some(&model), None));
+    }
+
+    #[test]
+    fn group_allowed_closed_beta_requires_group() {
+        let model = ModelAccessConfig {
+            closed_beta: true,
+            ..Default::default()
+        };
+
+        // `closed_beta` with an empty `allowed_groups` admits no one.
+        assert!(!is_group_allowed(Some(&model), Some("beta-testers")));
+        assert!(!is_group_allowed(Some(&model), None));
+    }
+
+    #[test]
+    fn group_allowed_closed_beta_with_allow_list() {
+        let model = ModelAccessConfig {
+            closed_beta: true,
+            allowed_groups: vec!["beta-testers".to_string()],
+            ..Default::default()
+        };
+
+        assert!(is_group_allowed(Some(&model), Some("beta-testers")));
+        assert!(!is_group_allowed(Some(&model), Some("everyone-else")));
+    }
 }
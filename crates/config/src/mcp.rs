@@ -7,6 +7,8 @@ use secrecy::SecretString;
 use serde::{Deserialize, Deserializer, de::Error};
 use url::Url;
 
+use crate::tls::TlsClientConfig;
+
 /// Configuration for MCP (Model Context Protocol) settings.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(default, deny_unknown_fields)]
@@ -19,6 +21,10 @@ pub struct McpConfig {
     pub downstream_cache: McpDownstreamCacheConfig,
     /// Map of server names to their configurations.
     pub servers: BTreeMap<String, McpServer>,
+    /// Overrides mapping a custom downstream JSON-RPC error code to a named
+    /// `error_type` metric label, for server-defined codes (e.g. `-32001`)
+    /// that would otherwise collapse into the generic `server_error` bucket.
+    pub error_type_overrides: BTreeMap<i32, String>,
 }
 
 /// Configuration for an individual MCP server.
@@ -60,6 +66,59 @@ impl McpServer {
             other => other.clone(),
         }
     }
+
+    /// The circuit breaker configured for this server, if any. Its absence
+    /// means calls to this server are never short-circuited, preserving the
+    /// default behavior of always contacting the downstream directly.
+    pub fn circuit_breaker(&self) -> Option<&CircuitBreakerConfig> {
+        match self {
+            McpServer::Stdio(config) => config.circuit_breaker.as_ref(),
+            McpServer::Http(config) => config.circuit_breaker.as_ref(),
+        }
+    }
+}
+
+/// Per-server circuit breaker configuration.
+///
+/// Tracks consecutive failures for a downstream server; once `failure_threshold`
+/// failures happen within `window`, the circuit opens and subsequent calls to
+/// that server short-circuit immediately instead of paying the full downstream
+/// latency. After `cooldown` elapses, the breaker enters half-open and lets a
+/// single probe call through: success closes the circuit, failure re-opens it.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CircuitBreakerConfig {
+    /// Number of consecutive failures within `window` required to open the circuit.
+    #[serde(default = "default_failure_threshold")]
+    pub failure_threshold: u32,
+    /// Sliding window that consecutive failures are counted within.
+    #[serde(default = "default_circuit_breaker_window", deserialize_with = "deserialize_duration")]
+    pub window: Duration,
+    /// How long the circuit stays open before allowing a single half-open probe call.
+    #[serde(default = "default_circuit_breaker_cooldown", deserialize_with = "deserialize_duration")]
+    pub cooldown: Duration,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: default_failure_threshold(),
+            window: default_circuit_breaker_window(),
+            cooldown: default_circuit_breaker_cooldown(),
+        }
+    }
+}
+
+fn default_failure_threshold() -> u32 {
+    5
+}
+
+fn default_circuit_breaker_window() -> Duration {
+    Duration::from_secs(60)
+}
+
+fn default_circuit_breaker_cooldown() -> Duration {
+    Duration::from_secs(30)
 }
 
 /// Configuration for downstream connection caching.
@@ -90,6 +149,7 @@ impl Default for McpConfig {
             path: "/mcp".to_string(),
             downstream_cache: McpDownstreamCacheConfig::default(),
             servers: BTreeMap::new(),
+            error_type_overrides: BTreeMap::new(),
         }
     }
 }
@@ -157,6 +217,11 @@ pub struct StdioConfig {
     /// Note: Due to rmcp library limitations, file redirection may not work as expected.
     #[serde(default = "default_stderr_target")]
     pub stderr: StdioTarget,
+
+    /// Circuit breaker protecting against repeated consecutive failures from
+    /// this server. Omit to always contact the server directly.
+    #[serde(default)]
+    pub circuit_breaker: Option<CircuitBreakerConfig>,
 }
 
 impl StdioConfig {
@@ -255,6 +320,10 @@ pub struct HttpConfig {
     /// Optional authentication configuration.
     #[serde(default)]
     pub auth: Option<ClientAuthConfig>,
+    /// Circuit breaker protecting against repeated consecutive failures from
+    /// this server. Omit to always contact the server directly.
+    #[serde(default)]
+    pub circuit_breaker: Option<CircuitBreakerConfig>,
 }
 
 impl HttpConfig {
@@ -291,41 +360,15 @@ impl HttpConfig {
     }
 }
 
-/// TLS configuration for HTTP-based MCP servers.
-#[derive(Debug, Clone, Deserialize)]
-#[serde(default, deny_unknown_fields)]
-pub struct TlsClientConfig {
-    /// Whether to verify TLS certificates.
-    pub verify_certs: bool,
-    /// Whether to accept invalid hostnames in TLS certificates.
-    pub accept_invalid_hostnames: bool,
-    /// Path to a custom root CA certificate file.
-    pub root_ca_cert_path: Option<PathBuf>,
-    /// Path to client certificate file for mutual TLS.
-    pub client_cert_path: Option<PathBuf>,
-    /// Path to client private key file for mutual TLS.
-    pub client_key_path: Option<PathBuf>,
-}
-
-impl Default for TlsClientConfig {
-    fn default() -> Self {
-        Self {
-            verify_certs: true,
-            accept_invalid_hostnames: false,
-            root_ca_cert_path: None,
-            client_cert_path: None,
-            client_key_path: None,
-        }
-    }
-}
-
 /// Authentication configuration for HTTP-based MCP servers.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "snake_case", untagged, deny_unknown_fields)]
 pub enum ClientAuthConfig {
     /// Token-based authentication.
     Token {
-        /// Authentication token to send with requests.
+        /// Authentication token to send with requests. Accepts a literal value,
+        /// `{ env = "VAR_NAME" }`, or `{ file = "/path" }`.
+        #[serde(deserialize_with = "crate::secret::deserialize_secret")]
         token: SecretString,
     },
     /// Forward the request authentication token to the MCP server.
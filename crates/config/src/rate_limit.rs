@@ -2,6 +2,7 @@
 
 use duration_str::{deserialize_duration, deserialize_option_duration};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::time::Duration;
 
 /// Rate limiting configuration for the server.
@@ -17,6 +18,52 @@ pub struct RateLimitConfig {
     pub global: Option<RateLimitQuota>,
     /// Rate limit per IP address.
     pub per_ip: Option<RateLimitQuota>,
+    /// Dynamic per-identity rate limit resolver.
+    ///
+    /// When the caller's identity resolves to a quota, that quota is enforced
+    /// in place of (not in addition to) `global`/`per_ip`. When the resolver
+    /// has no quota for this identity (or no identity is available, or no
+    /// resolver is configured), the static `global`/`per_ip` rules apply as usual.
+    pub resolver: Option<RateLimitResolverConfig>,
+    /// IETF "RateLimit header fields for HTTP" draft emitted on throttled responses.
+    #[serde(default)]
+    pub response_headers: RateLimitResponseHeaders,
+    /// Behavior when the storage backend itself fails (e.g. Redis is
+    /// unreachable), as opposed to a limit being legitimately exceeded.
+    #[serde(default)]
+    pub on_storage_error: StorageFailureMode,
+}
+
+/// Behavior when the rate limit storage backend itself fails (e.g. a Redis
+/// connection error), as distinct from a request legitimately exceeding its quota.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StorageFailureMode {
+    /// Reject the request, the same as if the limit were exceeded (default).
+    /// Safer under load, at the cost of a storage outage becoming a full
+    /// outage for every request subject to rate limiting.
+    #[default]
+    FailClosed,
+    /// Allow the request through and log the storage error. Keeps Nexus
+    /// available while the storage backend is down, at the cost of
+    /// temporarily losing rate limit enforcement.
+    FailOpen,
+}
+
+/// Selects which IETF rate limit response headers, if any, are attached to
+/// requests Nexus throttles - both at the HTTP layer (global/per-IP/identity)
+/// and, when the downstream surfaces retry info, on the MCP server/tool path.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RateLimitResponseHeaders {
+    /// Don't emit `RateLimit-*` headers (default). `Retry-After` is still
+    /// omitted, matching the existing behavior.
+    #[default]
+    None,
+    /// Emit `RateLimit-Limit`, `RateLimit-Remaining`, `RateLimit-Reset`, and
+    /// `Retry-After` following the IETF "RateLimit header fields for HTTP" draft-03.
+    #[serde(rename = "draft-03")]
+    Draft03,
 }
 
 /// Configuration for a rate limit quota.
@@ -47,6 +94,15 @@ pub enum StorageConfig {
     Memory,
     /// Redis storage with configuration.
     Redis(Box<RedisConfig>),
+    /// Redis Sentinel-monitored primary/replica set, for automatic failover.
+    Sentinel(Box<SentinelConfig>),
+    /// Redis Cluster, sharding keys across multiple nodes.
+    #[serde(rename = "redis-cluster")]
+    Cluster(Box<ClusterConfig>),
+    /// In-process mock of the Redis averaging-window algorithm, for tests
+    /// that want to exercise production limiter semantics without a live
+    /// Redis instance. Not intended for production use.
+    Mock,
 }
 
 impl Default for StorageConfig {
@@ -81,6 +137,25 @@ pub struct RedisConfig {
         deserialize_with = "deserialize_option_duration"
     )]
     pub connection_timeout: Option<Duration>,
+    /// Rate limiting algorithm used when checking and consuming quota.
+    #[serde(default)]
+    pub algorithm: RateLimitAlgorithm,
+}
+
+/// Rate limiting algorithm used by the Redis-backed storage backends.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RateLimitAlgorithm {
+    /// Averaging fixed window: blends the current and previous window's
+    /// counts, weighted by how far into the current window we are. Cheap
+    /// and simple, but allows a full burst at the start of every window.
+    #[default]
+    AveragingWindow,
+    /// GCRA (generic cell rate algorithm), a token-bucket variant that
+    /// tracks a single "theoretical arrival time" per key and spreads
+    /// allowed requests evenly across the interval instead of permitting a
+    /// burst at window boundaries.
+    Gcra,
 }
 
 fn default_key_prefix() -> Option<String> {
@@ -151,10 +226,185 @@ impl Default for RedisConfig {
             key_prefix: Some("nexus:rate_limit:".to_string()),
             response_timeout: Some(Duration::from_secs(1)),
             connection_timeout: Some(Duration::from_secs(5)),
+            algorithm: RateLimitAlgorithm::default(),
         }
     }
 }
 
+/// A single Redis Sentinel instance used to discover the current master.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SentinelNode {
+    /// Sentinel hostname or IP address.
+    pub host: String,
+    /// Sentinel port.
+    pub port: u16,
+}
+
+/// Redis Sentinel-monitored storage configuration.
+///
+/// The storage layer resolves the current master address via
+/// `SENTINEL get-master-addr-by-name` against the configured sentinel nodes,
+/// opens the connection pool against it, and re-resolves when opening a new
+/// connection to the resolved master fails or a pooled connection fails its
+/// PING health check on recycle (e.g. because the master was demoted and
+/// dropped its clients). A command error on a connection that still answers
+/// PING, such as `READONLY`, does not by itself trigger re-resolution. Once resolved, the `pool` and
+/// `tls` subsections apply to the connection to the master exactly as they do
+/// for a plain `redis` storage backend.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SentinelConfig {
+    /// Name of the master set as known to the sentinels.
+    pub master_name: String,
+    /// Sentinel instances to query for the current master address.
+    pub nodes: Vec<SentinelNode>,
+    /// Connection pool configuration for the connection to the resolved master.
+    #[serde(default)]
+    pub pool: RedisPoolConfig,
+    /// TLS configuration for the connection to the resolved master.
+    pub tls: Option<RedisTlsConfig>,
+    /// Key prefix for all rate limit keys.
+    #[serde(default = "default_key_prefix")]
+    pub key_prefix: Option<String>,
+    /// Response timeout for Redis commands.
+    #[serde(
+        default = "default_response_timeout",
+        deserialize_with = "deserialize_option_duration"
+    )]
+    pub response_timeout: Option<Duration>,
+    /// Connection timeout.
+    #[serde(
+        default = "default_connection_timeout",
+        deserialize_with = "deserialize_option_duration"
+    )]
+    pub connection_timeout: Option<Duration>,
+    /// Rate limiting algorithm used when checking and consuming quota.
+    #[serde(default)]
+    pub algorithm: RateLimitAlgorithm,
+}
+
+/// Redis Cluster storage configuration.
+///
+/// Every rate-limit key is wrapped in a `{...}` hash tag around its scope
+/// identifier (IP, server name, or the global bucket) so that the current- and
+/// previous-window keys for one limiter always land on the same cluster slot,
+/// letting the existing Lua scripts keep running atomically on a single node.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ClusterConfig {
+    /// Seed node connection URLs (redis:// or rediss:// for TLS); cluster topology
+    /// is discovered automatically from any reachable seed via `CLUSTER SLOTS`.
+    pub nodes: Vec<String>,
+    /// TLS configuration applied to every cluster node connection.
+    pub tls: Option<RedisTlsConfig>,
+    /// Key prefix for all rate limit keys.
+    #[serde(default = "default_key_prefix")]
+    pub key_prefix: Option<String>,
+    /// Response timeout for Redis commands.
+    #[serde(
+        default = "default_response_timeout",
+        deserialize_with = "deserialize_option_duration"
+    )]
+    pub response_timeout: Option<Duration>,
+    /// Connection timeout.
+    #[serde(
+        default = "default_connection_timeout",
+        deserialize_with = "deserialize_option_duration"
+    )]
+    pub connection_timeout: Option<Duration>,
+    /// Rate limiting algorithm used when checking and consuming quota.
+    #[serde(default)]
+    pub algorithm: RateLimitAlgorithm,
+}
+
+/// A single token- or request-based rate limit tier, used either as the
+/// per-user default or as a per-group override.
+///
+/// Exactly one of `input_token_limit`/`request_limit` is expected to be set
+/// for a given model: text-generation models are limited by input token
+/// count, while non-text models (image, audio) have no meaningful token
+/// count and are instead limited by number of requests.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TokenRateLimit {
+    /// Maximum input tokens allowed per `interval`. Used for text models.
+    #[serde(default)]
+    pub input_token_limit: Option<u64>,
+    /// Maximum number of requests allowed per `interval`. Used for non-text
+    /// models (e.g. image or audio generation) where token accounting doesn't apply.
+    #[serde(default)]
+    pub request_limit: Option<u64>,
+    /// Time window the limit applies to.
+    #[serde(deserialize_with = "deserialize_duration")]
+    pub interval: Duration,
+}
+
+/// Per-user rate limit configuration, with optional per-group overrides.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PerUserRateLimits {
+    /// Default maximum input tokens allowed per `interval`. Used for text models.
+    #[serde(default)]
+    pub input_token_limit: Option<u64>,
+    /// Default maximum number of requests allowed per `interval`. Used for
+    /// non-text models (e.g. image or audio generation) where token accounting doesn't apply.
+    #[serde(default)]
+    pub request_limit: Option<u64>,
+    /// Time window the default limit applies to.
+    #[serde(deserialize_with = "deserialize_duration")]
+    pub interval: Duration,
+    /// Per-group overrides of the default limit, keyed by group name.
+    #[serde(default)]
+    pub groups: BTreeMap<String, TokenRateLimit>,
+}
+
+/// Token rate limit configuration attachable to a provider or model.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TokenRateLimitsConfig {
+    /// Per-user limits, with optional per-group overrides.
+    pub per_user: Option<PerUserRateLimits>,
+}
+
+/// Configuration for resolving a rate limit quota per caller identity
+/// (API token, client ID, or authenticated subject) at request time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum RateLimitResolverConfig {
+    /// Identity -> quota map, configured directly in TOML.
+    Static(StaticResolverConfig),
+    /// External HTTP lookup, cached in-memory per identity to avoid
+    /// hammering the backing store on every request.
+    External(ExternalResolverConfig),
+}
+
+/// Static identity -> quota map for [`RateLimitResolverConfig::Static`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StaticResolverConfig {
+    /// Quota for each identity known ahead of time, keyed by the identity string.
+    pub limits: BTreeMap<String, RateLimitQuota>,
+}
+
+/// External HTTP lookup for [`RateLimitResolverConfig::External`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ExternalResolverConfig {
+    /// Endpoint queried with `?identity=<identity>`, expected to respond with
+    /// `{"limit": <u32>, "duration_secs": <u64>}` on a match, or a non-2xx
+    /// status (or a body missing those fields) when the identity has no quota.
+    pub url: String,
+    /// How long a resolved (or absent) quota is cached in-memory before the
+    /// endpoint is queried again for the same identity.
+    #[serde(default = "default_resolver_cache_ttl", deserialize_with = "deserialize_duration")]
+    pub cache_ttl: Duration,
+}
+
+fn default_resolver_cache_ttl() -> Duration {
+    Duration::from_secs(60)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -178,6 +428,17 @@ mod tests {
         "###);
     }
 
+    #[test]
+    fn deserialize_mock_storage() {
+        let toml = r#"
+            type = "mock"
+        "#;
+        let config: StorageConfig = toml::from_str(toml).unwrap();
+        insta::assert_debug_snapshot!(config, @r###"
+        Mock
+        "###);
+    }
+
     #[test]
     fn deserialize_redis_storage_minimal() {
         let toml = r#"
@@ -216,6 +477,7 @@ mod tests {
                 connection_timeout: Some(
                     5s,
                 ),
+                algorithm: AveragingWindow,
             },
         )
         "#);
@@ -292,11 +554,121 @@ mod tests {
                 connection_timeout: Some(
                     10s,
                 ),
+                algorithm: AveragingWindow,
             },
         )
         "###);
     }
 
+    #[test]
+    fn deserialize_redis_storage_gcra_algorithm() {
+        let toml = r#"
+            type = "redis"
+            url = "redis://localhost:6379/0"
+            algorithm = "gcra"
+        "#;
+        let config: StorageConfig = toml::from_str(toml).unwrap();
+
+        let StorageConfig::Redis(redis_config) = config else {
+            panic!("expected Redis storage config");
+        };
+
+        assert_eq!(redis_config.algorithm, RateLimitAlgorithm::Gcra);
+    }
+
+    #[test]
+    fn deserialize_sentinel_storage_minimal() {
+        let toml = r#"
+            type = "sentinel"
+            master_name = "mymaster"
+
+            [[nodes]]
+            host = "sentinel-1"
+            port = 26379
+
+            [[nodes]]
+            host = "sentinel-2"
+            port = 26379
+        "#;
+        let config: StorageConfig = toml::from_str(toml).unwrap();
+        insta::assert_debug_snapshot!(config, @r#"
+        Sentinel(
+            SentinelConfig {
+                master_name: "mymaster",
+                nodes: [
+                    SentinelNode {
+                        host: "sentinel-1",
+                        port: 26379,
+                    },
+                    SentinelNode {
+                        host: "sentinel-2",
+                        port: 26379,
+                    },
+                ],
+                pool: RedisPoolConfig {
+                    max_size: Some(
+                        16,
+                    ),
+                    min_idle: Some(
+                        0,
+                    ),
+                    timeout_create: Some(
+                        5s,
+                    ),
+                    timeout_wait: Some(
+                        5s,
+                    ),
+                    timeout_recycle: Some(
+                        300s,
+                    ),
+                },
+                tls: None,
+                key_prefix: Some(
+                    "nexus:rate_limit:",
+                ),
+                response_timeout: Some(
+                    1s,
+                ),
+                connection_timeout: Some(
+                    5s,
+                ),
+                algorithm: AveragingWindow,
+            },
+        )
+        "#);
+    }
+
+    #[test]
+    fn deserialize_cluster_storage_minimal() {
+        let toml = r#"
+            type = "redis-cluster"
+            nodes = ["redis://node-1:6379", "redis://node-2:6379", "redis://node-3:6379"]
+        "#;
+        let config: StorageConfig = toml::from_str(toml).unwrap();
+        insta::assert_debug_snapshot!(config, @r#"
+        Cluster(
+            ClusterConfig {
+                nodes: [
+                    "redis://node-1:6379",
+                    "redis://node-2:6379",
+                    "redis://node-3:6379",
+                ],
+                tls: None,
+                key_prefix: Some(
+                    "nexus:rate_limit:",
+                ),
+                response_timeout: Some(
+                    1s,
+                ),
+                connection_timeout: Some(
+                    5s,
+                ),
+                algorithm: AveragingWindow,
+            },
+        )
+        "#);
+    }
+
     #[test]
     fn rate_limit_config_with_storage() {
         let toml = r#"
@@ -348,6 +720,7 @@ mod tests {
                     connection_timeout: Some(
                         5s,
                     ),
+                    algorithm: AveragingWindow,
                 },
             ),
             global: Some(
@@ -362,7 +735,130 @@ mod tests {
                     duration: 60s,
                 },
             ),
+            resolver: None,
+            response_headers: None,
+            on_storage_error: FailClosed,
         }
         "#);
     }
+
+    #[test]
+    fn deserialize_static_resolver() {
+        let toml = r#"
+            enabled = true
+
+            [resolver]
+            type = "static"
+
+            [resolver.limits.alice]
+            limit = 100
+            duration = "60s"
+
+            [resolver.limits.bob]
+            limit = 10
+            duration = "1m"
+        "#;
+        let config: RateLimitConfig = toml::from_str(toml).unwrap();
+        insta::assert_debug_snapshot!(config.resolver, @r#"
+        Some(
+            Static(
+                StaticResolverConfig {
+                    limits: {
+                        "alice": RateLimitQuota {
+                            limit: 100,
+                            duration: 60s,
+                        },
+                        "bob": RateLimitQuota {
+                            limit: 10,
+                            duration: 60s,
+                        },
+                    },
+                },
+            ),
+        )
+        "#);
+    }
+
+    #[test]
+    fn deserialize_external_resolver_minimal() {
+        let toml = r#"
+            enabled = true
+
+            [resolver]
+            type = "external"
+            url = "https://quotas.example.com/lookup"
+        "#;
+        let config: RateLimitConfig = toml::from_str(toml).unwrap();
+        insta::assert_debug_snapshot!(config.resolver, @r#"
+        Some(
+            External(
+                ExternalResolverConfig {
+                    url: "https://quotas.example.com/lookup",
+                    cache_ttl: 60s,
+                },
+            ),
+        )
+        "#);
+    }
+
+    #[test]
+    fn deserialize_external_resolver_custom_ttl() {
+        let toml = r#"
+            enabled = true
+
+            [resolver]
+            type = "external"
+            url = "https://quotas.example.com/lookup"
+            cache_ttl = "5m"
+        "#;
+        let config: RateLimitConfig = toml::from_str(toml).unwrap();
+        insta::assert_debug_snapshot!(config.resolver, @r#"
+        Some(
+            External(
+                ExternalResolverConfig {
+                    url: "https://quotas.example.com/lookup",
+                    cache_ttl: 300s,
+                },
+            ),
+        )
+        "#);
+    }
+
+    #[test]
+    fn response_headers_default_is_none() {
+        let toml = r#"
+            enabled = true
+        "#;
+        let config: RateLimitConfig = toml::from_str(toml).unwrap();
+        insta::assert_debug_snapshot!(config.response_headers, @"None");
+    }
+
+    #[test]
+    fn deserialize_response_headers_draft03() {
+        let toml = r#"
+            enabled = true
+            response_headers = "draft-03"
+        "#;
+        let config: RateLimitConfig = toml::from_str(toml).unwrap();
+        insta::assert_debug_snapshot!(config.response_headers, @"Draft03");
+    }
+
+    #[test]
+    fn on_storage_error_default_is_fail_closed() {
+        let toml = r#"
+            enabled = true
+        "#;
+        let config: RateLimitConfig = toml::from_str(toml).unwrap();
+        insta::assert_debug_snapshot!(config.on_storage_error, @"FailClosed");
+    }
+
+    #[test]
+    fn deserialize_on_storage_error_fail_open() {
+        let toml = r#"
+            enabled = true
+            on_storage_error = "fail_open"
+        "#;
+        let config: RateLimitConfig = toml::from_str(toml).unwrap();
+        insta::assert_debug_snapshot!(config.on_storage_error, @"FailOpen");
+    }
 }
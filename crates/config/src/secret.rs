@@ -0,0 +1,63 @@
+//! Deserialization helpers that let secret fields (API keys, tokens, signing
+//! secrets) be sourced from an environment variable or a file, instead of
+//! only a literal string baked into the config.
+
+use std::path::PathBuf;
+
+use secrecy::SecretString;
+use serde::{Deserialize, Deserializer, de::Error};
+
+/// Raw shape of a secret field before resolution: either the literal secret
+/// value, a reference to an environment variable, or a reference to a file.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged, deny_unknown_fields)]
+enum SecretSource {
+    /// The secret given directly, e.g. `api_key = "sk-..."`.
+    Literal(String),
+    /// Read the secret from an environment variable at load time, e.g.
+    /// `api_key = { env = "OPENAI_API_KEY" }`.
+    Env {
+        /// Name of the environment variable to read.
+        env: String,
+    },
+    /// Read the secret from a file at load time, e.g.
+    /// `api_key = { file = "/run/secrets/openai" }`.
+    File {
+        /// Path to the file containing the secret.
+        file: PathBuf,
+    },
+}
+
+impl SecretSource {
+    fn resolve<E: Error>(self) -> Result<SecretString, E> {
+        match self {
+            SecretSource::Literal(value) => Ok(SecretString::from(value)),
+            SecretSource::Env { env } => std::env::var(&env)
+                .map(SecretString::from)
+                .map_err(|_| E::custom(format!("environment variable '{env}' is not set"))),
+            SecretSource::File { file } => std::fs::read_to_string(&file)
+                .map(|contents| SecretString::from(contents.trim().to_string()))
+                .map_err(|err| E::custom(format!("failed to read secret file '{}': {err}", file.display()))),
+        }
+    }
+}
+
+/// Deserialize a required secret field, accepting a literal string, `{ env = "..." }`,
+/// or `{ file = "..." }`.
+pub(crate) fn deserialize_secret<'de, D>(deserializer: D) -> Result<SecretString, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    SecretSource::deserialize(deserializer)?.resolve()
+}
+
+/// Deserialize an optional secret field, accepting a literal string, `{ env = "..." }`,
+/// or `{ file = "..." }`.
+pub(crate) fn deserialize_optional_secret<'de, D>(deserializer: D) -> Result<Option<SecretString>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<SecretSource>::deserialize(deserializer)?
+        .map(SecretSource::resolve)
+        .transpose()
+}
@@ -1,6 +1,6 @@
 //! HTTP server configuration settings.
 
-use std::net::SocketAddr;
+use std::net::{IpAddr, SocketAddr};
 
 use serde::Deserialize;
 
@@ -32,6 +32,12 @@ pub struct ServerConfig {
     /// Client identification configuration for token-based rate limiting
     #[serde(default)]
     pub client_identification: Option<ClientIdentificationConfig>,
+    /// IP addresses of trusted reverse proxies/load balancers sitting in front of Nexus.
+    /// When the request's direct peer address is in this list, `X-Forwarded-For` is trusted
+    /// to recover the real client address for `per_ip` rate limiting. Empty (the default)
+    /// means no proxy is trusted, and `per_ip` limits always key on the direct peer address.
+    #[serde(default)]
+    pub trusted_proxies: Vec<IpAddr>,
 }
 
 impl ServerConfig {
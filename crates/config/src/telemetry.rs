@@ -3,13 +3,17 @@ use std::collections::BTreeMap;
 use serde::Deserialize;
 
 pub mod exporters;
+pub mod host_metrics;
+pub mod log_levels;
 pub mod logs;
 pub mod metrics;
 pub mod tracing;
 
 pub use self::exporters::ExportersConfig;
+pub use self::host_metrics::HostMetricsConfig;
+pub use self::log_levels::LogSeverity;
 pub use self::logs::LogsConfig;
-pub use self::metrics::MetricsConfig;
+pub use self::metrics::{MetricViewConfig, MetricsConfig};
 pub use self::tracing::TracingConfig;
 
 /// Telemetry configuration for observability
@@ -38,6 +42,18 @@ pub struct TelemetryConfig {
     /// Logs-specific configuration
     #[serde(default)]
     logs: LogsConfig,
+
+    /// Periodic host/process resource metrics configuration
+    #[serde(default)]
+    host_metrics: HostMetricsConfig,
+
+    /// Overrides the tracing log level used for specific `error_type` values
+    /// (see the MCP tool-call metrics), e.g. `log_levels = { rate_limit_exceeded = "debug" }`.
+    /// Error types not listed here fall back to the built-in classification:
+    /// expected client-caused errors log at `debug`, everything else at `error`.
+    /// This only affects log verbosity - metric attributes are unchanged.
+    #[serde(default)]
+    log_levels: BTreeMap<String, LogSeverity>,
 }
 
 impl TelemetryConfig {
@@ -61,12 +77,27 @@ impl TelemetryConfig {
         &self.tracing
     }
 
+    /// Get the host/process resource metrics configuration
+    pub fn host_metrics(&self) -> &HostMetricsConfig {
+        &self.host_metrics
+    }
+
+    /// Get the configured per-`error_type` log level overrides
+    pub fn log_levels(&self) -> &BTreeMap<String, LogSeverity> {
+        &self.log_levels
+    }
+
     /// Get the exporters configuration for metrics
     /// Returns specific metrics exporters if configured, otherwise falls back to global
     pub fn metrics_exporters(&self) -> &ExportersConfig {
         self.metrics.exporters().unwrap_or(&self.exporters)
     }
 
+    /// Get the configured OpenTelemetry metric views
+    pub fn metrics_views(&self) -> &[MetricViewConfig] {
+        self.metrics.views()
+    }
+
     /// Get the exporters configuration for logs
     /// Returns specific logs exporters if configured, otherwise falls back to global
     pub fn logs_exporters(&self) -> &ExportersConfig {
@@ -97,6 +128,42 @@ impl TelemetryConfig {
         }
     }
 
+    /// Get the effective Prometheus configuration for metrics
+    /// Returns metrics-specific config if set and enabled, otherwise falls back to global config
+    pub fn metrics_prometheus_config(&self) -> Option<&exporters::PrometheusExporterConfig> {
+        // Check metrics-specific config first
+        if let Some(metrics_exporters) = self.metrics.exporters()
+            && metrics_exporters.prometheus.enabled
+        {
+            return Some(&metrics_exporters.prometheus);
+        }
+
+        // Fall back to global config
+        if self.exporters.prometheus.enabled {
+            Some(&self.exporters.prometheus)
+        } else {
+            None
+        }
+    }
+
+    /// Get the effective stdout configuration for metrics
+    /// Returns metrics-specific config if set and enabled, otherwise falls back to global config
+    pub fn metrics_stdout_config(&self) -> Option<&exporters::StdoutExporterConfig> {
+        // Check metrics-specific config first
+        if let Some(metrics_exporters) = self.metrics.exporters()
+            && metrics_exporters.stdout.enabled
+        {
+            return Some(&metrics_exporters.stdout);
+        }
+
+        // Fall back to global config
+        if self.exporters.stdout.enabled {
+            Some(&self.exporters.stdout)
+        } else {
+            None
+        }
+    }
+
     /// Get the effective OTLP configuration for traces
     /// Returns traces-specific config if set and enabled, otherwise falls back to global config
     pub fn traces_otlp_config(&self) -> Option<&exporters::OtlpExporterConfig> {
@@ -115,3 +182,62 @@ impl TelemetryConfig {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn metrics_stdout_config_disabled_by_default() {
+        let config: TelemetryConfig = toml::from_str("").unwrap();
+
+        assert!(config.metrics_stdout_config().is_none());
+    }
+
+    #[test]
+    fn metrics_stdout_config_falls_back_to_global() {
+        let config: TelemetryConfig = toml::from_str(
+            r#"
+            [exporters.stdout]
+            enabled = true
+        "#,
+        )
+        .unwrap();
+
+        assert!(config.metrics_stdout_config().is_some());
+    }
+
+    #[test]
+    fn metrics_stdout_config_prefers_metrics_specific_override() {
+        // Global stdout is off, but metrics has its own exporters block enabling it - the
+        // metrics-specific config should win rather than falling back to the (disabled) global one.
+        let config: TelemetryConfig = toml::from_str(
+            r#"
+            [metrics.exporters.stdout]
+            enabled = true
+        "#,
+        )
+        .unwrap();
+
+        assert!(config.metrics_stdout_config().is_some());
+    }
+
+    #[test]
+    fn metrics_stdout_config_metrics_specific_exporters_block_does_not_inherit_global_enable() {
+        // Once metrics declares its own `exporters` block, it's used as-is instead of merging
+        // with the global one - so leaving stdout unset there means disabled, even though the
+        // global block turned it on.
+        let config: TelemetryConfig = toml::from_str(
+            r#"
+            [exporters.stdout]
+            enabled = true
+
+            [metrics.exporters.otlp]
+            enabled = true
+        "#,
+        )
+        .unwrap();
+
+        assert!(config.metrics_stdout_config().is_none());
+    }
+}
@@ -1,5 +1,7 @@
 use duration_str::deserialize_duration;
 use serde::Deserialize;
+use std::borrow::Cow;
+use std::net::SocketAddr;
 use std::time::Duration;
 use url::Url;
 
@@ -10,6 +12,17 @@ pub struct ExportersConfig {
     /// OTLP exporter configuration
     #[serde(default)]
     pub otlp: OtlpExporterConfig,
+
+    /// Prometheus pull/scrape exporter configuration
+    #[serde(default)]
+    pub prometheus: PrometheusExporterConfig,
+
+    /// Stdout/JSON exporter configuration, for local debugging without a
+    /// collector. Can be enabled alongside `otlp` and/or `prometheus` - every
+    /// enabled exporter gets its own independently constructed reader on the
+    /// same meter provider, so turning this on never tears down the others.
+    #[serde(default)]
+    pub stdout: StdoutExporterConfig,
 }
 
 /// OTLP exporter configuration
@@ -58,6 +71,54 @@ impl ExportersConfig {
     pub fn otlp(&self) -> &OtlpExporterConfig {
         &self.otlp
     }
+
+    /// Get the Prometheus exporter configuration
+    pub fn prometheus(&self) -> &PrometheusExporterConfig {
+        &self.prometheus
+    }
+
+    /// Get the stdout exporter configuration
+    pub fn stdout(&self) -> &StdoutExporterConfig {
+        &self.stdout
+    }
+}
+
+/// Stdout/JSON exporter configuration. Writes each exported signal to
+/// stdout as a JSON line, for local debugging without standing up a
+/// collector.
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct StdoutExporterConfig {
+    /// Whether this exporter is enabled
+    pub enabled: bool,
+}
+
+/// Prometheus pull/scrape exporter configuration. Unlike OTLP, this doesn't
+/// push anywhere - it stands up a scrape endpoint on the server and waits
+/// for an external Prometheus (or compatible) instance to pull from it.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PrometheusExporterConfig {
+    /// Whether this exporter is enabled
+    pub enabled: bool,
+
+    /// The socket address the scrape endpoint should listen on. If unset,
+    /// the endpoint is exposed on the main server's listen address instead
+    /// of a dedicated port.
+    pub listen: Option<SocketAddr>,
+
+    /// The path for the scrape endpoint.
+    pub path: Cow<'static, str>,
+}
+
+impl Default for PrometheusExporterConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            listen: None,
+            path: Cow::Borrowed("/metrics"),
+        }
+    }
 }
 
 fn default_timeout() -> Duration {
@@ -107,3 +168,42 @@ impl Default for BatchExportConfig {
 fn default_scheduled_delay() -> Duration {
     Duration::from_secs(5)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prometheus_exporter_defaults() {
+        let config: ExportersConfig = toml::from_str("").unwrap();
+
+        insta::assert_debug_snapshot!(config.prometheus, @r###"
+        PrometheusExporterConfig {
+            enabled: false,
+            listen: None,
+            path: "/metrics",
+        }
+        "###);
+    }
+
+    #[test]
+    fn prometheus_exporter_custom() {
+        let toml = r#"
+            [prometheus]
+            enabled = true
+            listen = "0.0.0.0:9464"
+            path = "/custom-metrics"
+        "#;
+        let config: ExportersConfig = toml::from_str(toml).unwrap();
+
+        insta::assert_debug_snapshot!(config.prometheus, @r###"
+        PrometheusExporterConfig {
+            enabled: true,
+            listen: Some(
+                0.0.0.0:9464,
+            ),
+            path: "/custom-metrics",
+        }
+        "###);
+    }
+}
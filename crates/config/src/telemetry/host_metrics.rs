@@ -0,0 +1,121 @@
+use duration_str::deserialize_duration;
+use serde::Deserialize;
+use std::time::Duration;
+
+/// Periodic host and process resource metrics configuration
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct HostMetricsConfig {
+    /// Whether host/process metrics collection is enabled
+    pub enabled: bool,
+
+    /// How often to sample and emit the host/process gauges
+    #[serde(deserialize_with = "deserialize_duration", default = "default_interval")]
+    pub interval: Duration,
+
+    /// Which collectors are enabled
+    #[serde(default)]
+    pub collectors: CollectorsConfig,
+}
+
+impl Default for HostMetricsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval: default_interval(),
+            collectors: CollectorsConfig::default(),
+        }
+    }
+}
+
+fn default_interval() -> Duration {
+    Duration::from_secs(15)
+}
+
+/// Selects which host/process resource collectors are active. Disabling a
+/// collector means its gauges are never registered, so it won't appear in
+/// scraped metrics at all.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CollectorsConfig {
+    /// CPU utilization gauges
+    pub cpu: bool,
+
+    /// Memory usage gauges (total, used, available)
+    pub memory: bool,
+
+    /// Process-level gauges (RSS, open file descriptors)
+    pub process: bool,
+
+    /// Network interface counters
+    pub network: bool,
+
+    /// Disk I/O counters
+    pub disk: bool,
+}
+
+impl Default for CollectorsConfig {
+    fn default() -> Self {
+        Self {
+            cpu: true,
+            memory: true,
+            process: true,
+            network: true,
+            disk: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use insta::assert_debug_snapshot;
+
+    #[test]
+    fn host_metrics_defaults() {
+        let config: HostMetricsConfig = toml::from_str("").unwrap();
+
+        assert_debug_snapshot!(config, @r###"
+        HostMetricsConfig {
+            enabled: false,
+            interval: 15s,
+            collectors: CollectorsConfig {
+                cpu: true,
+                memory: true,
+                process: true,
+                network: true,
+                disk: true,
+            },
+        }
+        "###);
+    }
+
+    #[test]
+    fn host_metrics_partial_collectors() {
+        let config: HostMetricsConfig = toml::from_str(
+            r#"
+            enabled = true
+            interval = "30s"
+
+            [collectors]
+            network = false
+            disk = false
+        "#,
+        )
+        .unwrap();
+
+        assert_debug_snapshot!(config, @r###"
+        HostMetricsConfig {
+            enabled: true,
+            interval: 30s,
+            collectors: CollectorsConfig {
+                cpu: true,
+                memory: true,
+                process: true,
+                network: false,
+                disk: false,
+            },
+        }
+        "###);
+    }
+}
@@ -0,0 +1,52 @@
+use serde::Deserialize;
+
+/// A log severity level, deserializable from config (e.g. `"debug"`).
+///
+/// Mirrors [`log::Level`] so callers can map straight into it without
+/// re-deriving the ordering or pulling in `log`'s own (feature-gated) serde
+/// support.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogSeverity {
+    /// Most verbose level, for fine-grained diagnostic output.
+    Trace,
+    /// Diagnostic information useful during development.
+    Debug,
+    /// Informational messages about normal operation.
+    Info,
+    /// Indicates a potentially harmful situation.
+    Warn,
+    /// Indicates an error that should be investigated.
+    Error,
+}
+
+impl LogSeverity {
+    /// The corresponding [`log::Level`].
+    pub fn as_log_level(self) -> log::Level {
+        match self {
+            LogSeverity::Trace => log::Level::Trace,
+            LogSeverity::Debug => log::Level::Debug,
+            LogSeverity::Info => log::Level::Info,
+            LogSeverity::Warn => log::Level::Warn,
+            LogSeverity::Error => log::Level::Error,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    struct Wrapper {
+        level: LogSeverity,
+    }
+
+    #[test]
+    fn deserializes_from_lowercase_strings() {
+        let wrapper: Wrapper = toml::from_str(r#"level = "debug""#).unwrap();
+
+        assert_eq!(wrapper.level, LogSeverity::Debug);
+    }
+}
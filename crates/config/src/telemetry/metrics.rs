@@ -8,6 +8,11 @@ pub struct MetricsConfig {
     /// Override global exporters for metrics (optional)
     #[serde(default)]
     exporters: Option<ExportersConfig>,
+
+    /// Custom OpenTelemetry views, for tuning bucket boundaries and
+    /// attribute cardinality on specific instruments
+    #[serde(default)]
+    views: Vec<MetricViewConfig>,
 }
 
 impl MetricsConfig {
@@ -15,4 +20,107 @@ impl MetricsConfig {
     pub fn exporters(&self) -> Option<&ExportersConfig> {
         self.exporters.as_ref()
     }
+
+    /// Get the configured metric views
+    pub fn views(&self) -> &[MetricViewConfig] {
+        &self.views
+    }
+}
+
+/// Configuration for a single OpenTelemetry metric view. Lets operators
+/// override histogram bucket boundaries and control attribute cardinality
+/// for instruments matching `name`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MetricViewConfig {
+    /// Instrument name to match, e.g. `mcp.tool.call.duration`. A trailing
+    /// `*` matches any instrument name with that prefix, e.g. `mcp.*`.
+    pub name: String,
+
+    /// Explicit histogram bucket boundaries, in milliseconds. Ignored for
+    /// instruments that aren't histograms.
+    #[serde(default)]
+    pub bucket_boundaries: Option<Vec<f64>>,
+
+    /// Attribute keys to exclude from exported series for this instrument.
+    /// Only effective for instruments Nexus knows the full attribute set
+    /// of (see the duration histograms this feature was built for); has no
+    /// effect on other instruments unless `allowed_attributes` is also set.
+    #[serde(default)]
+    pub drop_attributes: Vec<String>,
+
+    /// Explicit allowlist of attribute keys to keep on exported series.
+    /// Takes precedence over `drop_attributes` when set.
+    #[serde(default)]
+    pub allowed_attributes: Option<Vec<String>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use insta::assert_debug_snapshot;
+
+    #[test]
+    fn metrics_config_defaults() {
+        let config: MetricsConfig = toml::from_str("").unwrap();
+
+        assert_debug_snapshot!(config, @r###"
+        MetricsConfig {
+            exporters: None,
+            views: [],
+        }
+        "###);
+    }
+
+    #[test]
+    fn metrics_config_views() {
+        let config: MetricsConfig = toml::from_str(
+            r#"
+            [[views]]
+            name = "mcp.tool.call.duration"
+            bucket_boundaries = [5.0, 10.0, 25.0, 50.0, 100.0, 250.0, 500.0, 1000.0]
+            drop_attributes = ["client.id"]
+
+            [[views]]
+            name = "mcp.*"
+            allowed_attributes = ["tool_name", "status"]
+        "#,
+        )
+        .unwrap();
+
+        assert_debug_snapshot!(config.views, @r###"
+        [
+            MetricViewConfig {
+                name: "mcp.tool.call.duration",
+                bucket_boundaries: Some(
+                    [
+                        5.0,
+                        10.0,
+                        25.0,
+                        50.0,
+                        100.0,
+                        250.0,
+                        500.0,
+                        1000.0,
+                    ],
+                ),
+                drop_attributes: [
+                    "client.id",
+                ],
+                allowed_attributes: None,
+            },
+            MetricViewConfig {
+                name: "mcp.*",
+                bucket_boundaries: None,
+                drop_attributes: [],
+                allowed_attributes: Some(
+                    [
+                        "tool_name",
+                        "status",
+                    ],
+                ),
+            },
+        ]
+        "###);
+    }
 }
@@ -101,7 +101,7 @@ impl Default for CollectConfig {
 }
 
 /// Trace context propagation configuration
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(default, deny_unknown_fields)]
 pub struct PropagationConfig {
     /// W3C Trace Context propagation
@@ -117,6 +117,17 @@ pub struct PropagationConfig {
     pub jaeger: bool,
 }
 
+impl Default for PropagationConfig {
+    fn default() -> Self {
+        Self {
+            trace_context: true,
+            baggage: true,
+            aws_xray: false,
+            jaeger: false,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -138,8 +149,8 @@ mod tests {
                 max_attributes_per_link: 128,
             },
             propagation: PropagationConfig {
-                trace_context: false,
-                baggage: false,
+                trace_context: true,
+                baggage: true,
                 aws_xray: false,
                 jaeger: false,
             },
@@ -171,8 +182,8 @@ mod tests {
                 max_attributes_per_link: 128,
             },
             propagation: PropagationConfig {
-                trace_context: false,
-                baggage: false,
+                trace_context: true,
+                baggage: true,
                 aws_xray: false,
                 jaeger: false,
             },
@@ -257,6 +268,14 @@ mod tests {
                             max_concurrent_exports: 1,
                         },
                     },
+                    prometheus: PrometheusExporterConfig {
+                        enabled: false,
+                        listen: None,
+                        path: "/metrics",
+                    },
+                    stdout: StdoutExporterConfig {
+                        enabled: false,
+                    },
                 },
             ),
         }
@@ -311,7 +330,7 @@ sampling rate must be between 0.0 and 1.0, got -0.1
         assert_debug_snapshot!(config.propagation, @r###"
         PropagationConfig {
             trace_context: true,
-            baggage: false,
+            baggage: true,
             aws_xray: true,
             jaeger: false,
         }
@@ -361,6 +380,14 @@ sampling rate must be between 0.0 and 1.0, got -0.1
                     max_concurrent_exports: 1,
                 },
             },
+            prometheus: PrometheusExporterConfig {
+                enabled: false,
+                listen: None,
+                path: "/metrics",
+            },
+            stdout: StdoutExporterConfig {
+                enabled: false,
+            },
         }
         "###);
     }
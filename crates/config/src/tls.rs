@@ -1,7 +1,8 @@
 //! TLS configuration for secure connections.
 
-use std::path::PathBuf;
+use std::{fs, io::Read, path::PathBuf};
 
+use reqwest::{Certificate, ClientBuilder, Identity};
 use serde::Deserialize;
 
 /// TLS configuration for secure connections.
@@ -13,3 +14,105 @@ pub struct TlsServerConfig {
     /// Path to the TLS private key PEM file.
     pub key: PathBuf,
 }
+
+/// TLS configuration for outbound client connections, e.g. to MCP servers or LLM providers.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TlsClientConfig {
+    /// Whether to verify TLS certificates.
+    pub verify_certs: bool,
+    /// Whether to accept invalid hostnames in TLS certificates.
+    pub accept_invalid_hostnames: bool,
+    /// Base set of trusted root certificates to validate the server chain against.
+    pub root_certificates: RootCertificateSource,
+    /// Path to a custom root CA certificate file. In [`RootCertificateSource::Custom`] mode
+    /// this is the sole trust anchor; in [`RootCertificateSource::System`] or
+    /// [`RootCertificateSource::Webpki`] mode it's trusted in addition to the chosen base.
+    pub root_ca_cert_path: Option<PathBuf>,
+    /// Path to client certificate file for mutual TLS.
+    pub client_cert_path: Option<PathBuf>,
+    /// Path to client private key file for mutual TLS.
+    pub client_key_path: Option<PathBuf>,
+}
+
+impl Default for TlsClientConfig {
+    fn default() -> Self {
+        Self {
+            verify_certs: true,
+            accept_invalid_hostnames: false,
+            root_certificates: RootCertificateSource::default(),
+            root_ca_cert_path: None,
+            client_cert_path: None,
+            client_key_path: None,
+        }
+    }
+}
+
+/// Base set of trusted root certificates for outbound TLS connections.
+#[derive(Debug, Clone, Copy, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RootCertificateSource {
+    /// Load the OS trust store via `rustls-native-certs` (default). Container images that
+    /// ship without a system trust store will fail TLS verification in this mode.
+    #[default]
+    System,
+    /// Use the bundled `webpki-roots` set instead of the OS trust store, for hermetic builds
+    /// or containers with no system trust store.
+    Webpki,
+    /// Trust only the PEM bundle at `root_ca_cert_path`, ignoring the system and bundled roots.
+    Custom,
+}
+
+/// Applies these TLS client settings (custom root CA, client certificate for mutual TLS, and
+/// certificate/hostname verification toggles) to a [`reqwest::ClientBuilder`].
+///
+/// Shared by every outbound HTTP client in Nexus that presents a client identity to an upstream
+/// server over TLS (LLM providers, downstream MCP servers), so the root-certificate-source
+/// handling lives in exactly one place.
+pub fn apply_to_reqwest_builder(mut builder: ClientBuilder, tls: &TlsClientConfig) -> anyhow::Result<ClientBuilder> {
+    builder = builder
+        .danger_accept_invalid_certs(!tls.verify_certs)
+        .danger_accept_invalid_hostnames(tls.accept_invalid_hostnames);
+
+    builder = match tls.root_certificates {
+        RootCertificateSource::System => builder.tls_built_in_native_certs(true).tls_built_in_webpki_certs(false),
+        RootCertificateSource::Webpki => builder.tls_built_in_native_certs(false).tls_built_in_webpki_certs(true),
+        RootCertificateSource::Custom => builder.tls_built_in_native_certs(false).tls_built_in_webpki_certs(false),
+    };
+
+    if tls.root_certificates == RootCertificateSource::Custom && tls.root_ca_cert_path.is_none() {
+        anyhow::bail!("TLS root_certificates is set to 'custom' but no root_ca_cert_path was provided");
+    }
+
+    if let Some(ref path) = tls.root_ca_cert_path {
+        let mut pem = Vec::new();
+
+        let mut file = fs::File::open(path)?;
+        file.read_to_end(&mut pem)?;
+
+        let cert = Certificate::from_pem(&pem)?;
+        builder = builder.add_root_certificate(cert);
+    }
+
+    let identity = tls.client_cert_path.as_ref().zip(tls.client_key_path.as_ref());
+
+    if let Some((cert_path, key_path)) = identity {
+        let mut cert_pem = Vec::new();
+        let mut cert_file = fs::File::open(cert_path)?;
+        cert_file.read_to_end(&mut cert_pem)?;
+
+        let mut key_pem = Vec::new();
+        let mut key_file = fs::File::open(key_path)?;
+        key_file.read_to_end(&mut key_pem)?;
+
+        let mut combined_pem = Vec::new();
+        combined_pem.extend_from_slice(&cert_pem);
+        combined_pem.extend_from_slice(b"\n");
+        combined_pem.extend_from_slice(&key_pem);
+
+        let identity = Identity::from_pem(&combined_pem)?;
+        builder = builder.identity(identity);
+    }
+
+    Ok(builder)
+}
@@ -61,6 +61,7 @@ pub struct TestService {
     require_auth: bool,
     expected_token: Option<String>,
     forward_auth: bool,
+    received_headers: Arc<std::sync::Mutex<Option<HeaderMap>>>,
 }
 
 #[derive(Clone)]
@@ -99,6 +100,7 @@ impl TestService {
             require_auth: false,
             expected_token: None,
             forward_auth: false,
+            received_headers: Arc::new(std::sync::Mutex::new(None)),
         }
     }
 
@@ -168,6 +170,12 @@ impl TestService {
         self.forward_auth
     }
 
+    /// Returns the headers of the most recent request this service received, so tests can
+    /// assert on what Nexus actually sent downstream (e.g. forwarded trace context).
+    pub fn received_headers(&self) -> Option<HeaderMap> {
+        self.received_headers.lock().unwrap().clone()
+    }
+
     pub fn get_tls_cert_paths(&self) -> Option<(PathBuf, PathBuf)> {
         self.tls_config
             .as_ref()
@@ -209,6 +217,13 @@ async fn spawn_sse(service: TestService) -> (SocketAddr, CancellationToken) {
     let (sse_server, mut router) = SseServer::new(sse_config);
     let tls_config = service.tls_config.clone();
 
+    // Capture the headers of every request for test assertions
+    let received_headers = service.received_headers.clone();
+    router = router.layer(middleware::from_fn(move |headers: HeaderMap, request: Request, next: Next| {
+        let received_headers = received_headers.clone();
+        async move { capture_headers_middleware(headers, request, next, received_headers).await }
+    }));
+
     // Add authentication middleware if required
     if service.requires_auth() {
         let expected_token = service.get_expected_token().cloned();
@@ -277,6 +292,7 @@ async fn spawn_streamable_http(service: TestService) -> SocketAddr {
     let tls_config = service.tls_config.clone();
     let requires_auth = service.requires_auth();
     let expected_token = service.get_expected_token().cloned();
+    let received_headers = service.received_headers.clone();
 
     let mcp_service = StreamableHttpService::new(
         move || Ok(service.clone()),
@@ -289,6 +305,12 @@ async fn spawn_streamable_http(service: TestService) -> SocketAddr {
 
     let mut app = Router::new().route_service("/mcp", mcp_service);
 
+    // Capture the headers of every request for test assertions
+    app = app.layer(middleware::from_fn(move |headers: HeaderMap, request: Request, next: Next| {
+        let received_headers = received_headers.clone();
+        async move { capture_headers_middleware(headers, request, next, received_headers).await }
+    }));
+
     // Add authentication middleware if required
     if requires_auth {
         app = app.layer(middleware::from_fn(
@@ -425,6 +447,18 @@ impl ServerHandler for TestService {
     }
 }
 
+/// Middleware that records the headers of every incoming request, so tests can inspect what
+/// Nexus actually sent downstream (e.g. forwarded auth or trace context).
+async fn capture_headers_middleware(
+    headers: HeaderMap,
+    request: Request,
+    next: Next,
+    received_headers: Arc<std::sync::Mutex<Option<HeaderMap>>>,
+) -> Response {
+    *received_headers.lock().unwrap() = Some(headers);
+    next.run(request).await
+}
+
 /// Middleware that validates Bearer token authentication
 async fn auth_middleware(
     headers: HeaderMap,
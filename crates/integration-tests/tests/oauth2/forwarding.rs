@@ -1353,6 +1353,88 @@ async fn mixed_success_failure_scenarios() {
     mcp_client.disconnect().await;
 }
 
+// =============================================================================
+// Trace Context Propagation Tests
+// =============================================================================
+
+#[tokio::test]
+async fn trace_context_forwarded_to_downstream() {
+    let (_nexus_server, access_token) = setup_hydra_test().await.unwrap();
+
+    let mut dynamic_service = TestService::streamable_http("traced_server".to_string())
+        .with_required_auth_token(access_token.clone())
+        .with_forward_auth();
+    dynamic_service.add_tool(AdderTool);
+
+    // Keep a handle around: `TestService` shares its `received_headers` state across clones,
+    // so this still reflects what the spawned copy actually received.
+    let downstream = dynamic_service.clone();
+
+    let mut server_builder = TestServer::builder();
+    server_builder.spawn_service(dynamic_service).await;
+
+    // Enabling `[telemetry]` turns on the (default-on) tracing layer, which is what extracts
+    // `traceparent`/`baggage` from inbound requests in the first place.
+    let config = format!(
+        "{}\n[telemetry]\nservice_name = \"trace-forwarding-test\"\n",
+        oauth_config_with_forwarding()
+    );
+
+    let server = server_builder.build(&config).await;
+
+    let trace_id = format!("{:032x}", uuid::Uuid::new_v4().as_u128());
+    let span_id = format!("{:016x}", rand::random::<u64>());
+    let traceparent = format!("00-{trace_id}-{span_id}-01");
+
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert("Authorization", format!("Bearer {access_token}").parse().unwrap());
+    headers.insert("traceparent", traceparent.parse().unwrap());
+    headers.insert("baggage", "team=payments".parse().unwrap());
+
+    let mcp_client = server.mcp_client_with_headers("/mcp", headers).await;
+
+    // This is the server's first request to this dynamic downstream, so it's a cache miss -
+    // exactly the case where the trace headers get baked into the new connection.
+    let result = mcp_client
+        .execute("traced_server__adder", json!({"a": 1, "b": 2}))
+        .await;
+
+    insta::assert_json_snapshot!(result, @r###"
+    {
+      "content": [
+        {
+          "type": "text",
+          "text": "1 + 2 = 3"
+        }
+      ],
+      "isError": false
+    }
+    "###);
+
+    let received = downstream
+        .received_headers()
+        .expect("downstream should have received at least one request");
+
+    // The span id changes (the gateway creates its own child span for the downstream call), but
+    // the trace id must be preserved so the whole request is one trace end-to-end.
+    let received_traceparent = received
+        .get("traceparent")
+        .and_then(|v| v.to_str().ok())
+        .expect("traceparent header should have been forwarded downstream");
+    assert!(
+        received_traceparent.contains(&trace_id),
+        "forwarded traceparent '{received_traceparent}' should carry the original trace id '{trace_id}'"
+    );
+
+    assert_eq!(
+        received.get("baggage").and_then(|v| v.to_str().ok()),
+        Some("team=payments"),
+        "baggage should be forwarded downstream unchanged"
+    );
+
+    mcp_client.disconnect().await;
+}
+
 // =============================================================================
 // Regression and Compatibility Tests
 // =============================================================================
@@ -1,5 +1,7 @@
 //! Integration tests for rate limiting functionality.
 
+mod mock;
+
 use indoc::indoc;
 use integration_tests::{TestServer, TestService, tools::AdderTool};
 use serde_json::json;
@@ -72,6 +74,7 @@ async fn global_rate_limit_basic() {
 async fn per_ip_rate_limit_basic() {
     let config = indoc! {r#"
         [server]
+        trusted_proxies = ["127.0.0.1"]
         [server.rate_limit]
         enabled = true
         [server.rate_limit.per_ip]
@@ -85,6 +88,8 @@ async fn per_ip_rate_limit_basic() {
 
     let server = TestServer::builder().build(config).await;
 
+    // The test client connects from 127.0.0.1, which is configured as a trusted proxy above,
+    // so X-Forwarded-For is honored to distinguish these "different" IPs.
     // Test requests from different IPs using X-Forwarded-For header
     let mut results = Vec::new();
 
@@ -172,6 +177,70 @@ async fn per_ip_rate_limit_basic() {
     "#);
 }
 
+#[tokio::test]
+async fn per_ip_rate_limit_ignores_forwarded_for_without_trusted_proxy() {
+    // No `trusted_proxies` configured, so a forged X-Forwarded-For from an untrusted
+    // direct peer must be ignored - every request here should be keyed on the real
+    // connecting peer address instead, regardless of the (different) spoofed IPs below.
+    let config = indoc! {r#"
+        [server]
+        [server.rate_limit]
+        enabled = true
+        [server.rate_limit.per_ip]
+        limit = 2
+        duration = "10s"
+
+        [mcp]
+        enabled = true
+        path = "/mcp"
+    "#};
+
+    let server = TestServer::builder().build(config).await;
+
+    let mut results = Vec::new();
+
+    for (i, spoofed_ip) in ["203.0.113.1", "203.0.113.2", "203.0.113.3", "203.0.113.4"]
+        .into_iter()
+        .enumerate()
+    {
+        let response = server
+            .client
+            .request(reqwest::Method::GET, "/health")
+            .header("X-Forwarded-For", spoofed_ip)
+            .send()
+            .await
+            .unwrap();
+
+        results.push(json!({
+            "request": i + 1,
+            "status": response.status().as_u16()
+        }));
+    }
+
+    // All four requests share a single per-IP bucket (the real, untrusted peer address),
+    // so the limit of 2 is hit despite every request claiming a distinct forwarded IP.
+    insta::assert_json_snapshot!(results, @r#"
+    [
+      {
+        "request": 1,
+        "status": 200
+      },
+      {
+        "request": 2,
+        "status": 200
+      },
+      {
+        "request": 3,
+        "status": 429
+      },
+      {
+        "request": 4,
+        "status": 429
+      }
+    ]
+    "#);
+}
+
 #[tokio::test]
 async fn mcp_server_rate_limit() {
     let mut builder = TestServer::builder();
@@ -433,6 +502,9 @@ async fn rate_limiting_disabled() {
 #[tokio::test]
 async fn mixed_rate_limits() {
     let config = indoc! {r#"
+        [server]
+        trusted_proxies = ["127.0.0.1"]
+
         [server.rate_limit]
         enabled = true
 
@@ -0,0 +1,142 @@
+#![allow(clippy::panic)]
+
+use indoc::indoc;
+use integration_tests::{TestServer, TestService, tools::AdderTool};
+use serde_json::json;
+
+#[tokio::test]
+async fn response_headers_default_to_none() {
+    let config = indoc! {r#"
+        [server.rate_limits]
+        enabled = true
+
+        [server.rate_limits.global]
+        limit = 1
+        duration = "10s"
+
+        [mcp]
+        enabled = true
+
+        # Dummy server to satisfy validation
+        [mcp.servers.dummy]
+        cmd = ["echo", "dummy"]
+    "#};
+
+    let server = TestServer::builder().build(config).await;
+
+    // Exhaust the limit, then check the throttled response.
+    server.client.get("/health").await;
+    let response = server.client.get("/health").await;
+
+    assert_eq!(response.status().as_u16(), 429);
+    assert!(response.headers().get("RateLimit-Limit").is_none());
+    assert!(response.headers().get("Retry-After").is_none());
+}
+
+#[tokio::test]
+async fn global_rate_limit_emits_draft03_headers() {
+    let config = indoc! {r#"
+        [server.rate_limits]
+        enabled = true
+        response_headers = "draft-03"
+
+        [server.rate_limits.global]
+        limit = 1
+        duration = "10s"
+
+        [mcp]
+        enabled = true
+
+        # Dummy server to satisfy validation
+        [mcp.servers.dummy]
+        cmd = ["echo", "dummy"]
+    "#};
+
+    let server = TestServer::builder().build(config).await;
+
+    // First request consumes the only token in the window.
+    server.client.get("/health").await;
+
+    // Second request is throttled by the HTTP-layer global check.
+    let response = server.client.get("/health").await;
+
+    assert_eq!(response.status().as_u16(), 429);
+
+    let headers = response.headers();
+
+    assert_eq!(headers.get("RateLimit-Limit").unwrap(), "1");
+    assert_eq!(headers.get("RateLimit-Remaining").unwrap(), "0");
+
+    // The header and the metric are derived from the same limiter state, so
+    // RateLimit-Reset and Retry-After must agree.
+    assert_eq!(
+        headers.get("RateLimit-Reset").unwrap(),
+        headers.get("Retry-After").unwrap()
+    );
+}
+
+#[tokio::test]
+async fn mcp_tool_rate_limit_emits_draft03_headers() {
+    let mut builder = TestServer::builder();
+
+    let mut service = TestService::streamable_http("test_server".to_string());
+    service.add_tool(AdderTool);
+    builder.spawn_service(service).await;
+
+    let config = indoc! {r#"
+        [server.rate_limits]
+        enabled = true
+        response_headers = "draft-03"
+
+        [mcp.servers.test_server.rate_limits]
+        limit = 1
+        interval = "10s"
+    "#};
+
+    let server = builder.build(config).await;
+
+    let call_tool = |id: u64| {
+        json!({
+            "jsonrpc": "2.0",
+            "method": "tools/call",
+            "params": {
+                "name": "test_server__adder",
+                "arguments": {"a": 1, "b": 2}
+            },
+            "id": id
+        })
+    };
+
+    // First call succeeds and consumes the only token in the window.
+    server
+        .client
+        .request(reqwest::Method::POST, "/mcp")
+        .header("Content-Type", "application/json")
+        .header("Accept", "application/json, text/event-stream")
+        .json(&call_tool(1))
+        .send()
+        .await
+        .unwrap();
+
+    // Second call is rejected deep inside MCP tool-call handling - past the point where the
+    // HTTP rate limit layer still has a response to attach headers to - yet the headers must
+    // still show up on the wrapping HTTP response via the decision slot.
+    let response = server
+        .client
+        .request(reqwest::Method::POST, "/mcp")
+        .header("Content-Type", "application/json")
+        .header("Accept", "application/json, text/event-stream")
+        .json(&call_tool(2))
+        .send()
+        .await
+        .unwrap();
+
+    // JSON-RPC errors are carried in the response body, not the HTTP status.
+    assert_eq!(response.status().as_u16(), 200);
+
+    let headers = response.headers();
+
+    assert_eq!(headers.get("RateLimit-Limit").unwrap(), "1");
+    assert_eq!(headers.get("RateLimit-Remaining").unwrap(), "0");
+    assert!(headers.get("RateLimit-Reset").is_some());
+}
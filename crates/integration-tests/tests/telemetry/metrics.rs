@@ -3,9 +3,12 @@
 use clickhouse::Row;
 use serde::{Deserialize, Serialize};
 
+mod host;
 mod llm;
 mod mcp;
+mod prometheus;
 mod redis;
+mod views;
 
 /// Row structure for histogram metrics in ClickHouse
 #[derive(Row, Deserialize, Serialize, Debug, Clone)]
@@ -30,10 +33,7 @@ pub struct SumMetricRow {
 #[derive(Row, Deserialize, Debug, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct GaugeMetricRow {
-    #[allow(dead_code)]
     pub metric_name: String,
-    #[allow(dead_code)]
     pub attributes: Vec<(String, String)>,
-    #[allow(dead_code)]
     pub value: f64,
 }
@@ -0,0 +1,54 @@
+//! Host/process resource metrics tests
+
+use indoc::formatdoc;
+use integration_tests::{TestServer, telemetry::*};
+
+use crate::telemetry::metrics::GaugeMetricRow;
+
+#[tokio::test]
+async fn host_metrics_are_emitted() {
+    let service_name = unique_service_name("host-metrics");
+
+    let config = formatdoc! {r#"
+        [telemetry]
+        service_name = "{service_name}"
+
+        [telemetry.exporters.otlp]
+        enabled = true
+        endpoint = "http://localhost:4317"
+        protocol = "grpc"
+
+        [telemetry.exporters.otlp.batch_export]
+        scheduled_delay = "1s"
+        max_export_batch_size = 100
+
+        [telemetry.host_metrics]
+        enabled = true
+        interval = "50ms"
+    "#};
+
+    let _test_server = TestServer::builder().build(&config).await;
+
+    let clickhouse = create_clickhouse_client().await;
+
+    let query = formatdoc! {r#"
+        SELECT MetricName, Attributes, Value
+        FROM otel_metrics_gauge
+        WHERE
+            MetricName = 'system.memory.usage'
+            AND ServiceName = '{service_name}'
+        ORDER BY TimeUnix DESC
+    "#};
+
+    let rows = wait_for_metrics_matching::<GaugeMetricRow, _>(&clickhouse, &query, |rows| !rows.is_empty())
+        .await
+        .expect("Failed to get host memory metrics");
+
+    let states: std::collections::BTreeSet<_> = rows
+        .iter()
+        .filter_map(|row| row.attributes.iter().find(|(k, _)| k == "state").map(|(_, v)| v.clone()))
+        .collect();
+
+    // Expected: memory gauges are reported per `state` (used/available/total)
+    assert!(states.contains("used"));
+}
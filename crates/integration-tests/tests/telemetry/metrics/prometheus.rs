@@ -0,0 +1,82 @@
+//! Prometheus scrape endpoint metrics tests
+
+use indoc::formatdoc;
+use integration_tests::{TestServer, TestService, telemetry::*, tools::AdderTool};
+use std::time::Duration;
+
+fn create_test_config_with_prometheus(service_name: &str) -> String {
+    formatdoc! {r#"
+        [server]
+        listen_address = "127.0.0.1:0"
+
+        [telemetry]
+        service_name = "{service_name}"
+
+        [telemetry.exporters.prometheus]
+        enabled = true
+
+        [mcp]
+        enabled = true
+        path = "/mcp"
+    "#}
+}
+
+/// Scrapes `/metrics`, retrying until the body contains `needle` or the
+/// attempts are exhausted. The Prometheus reader renders metrics
+/// synchronously on scrape, but the request that produces them may not have
+/// completed yet.
+async fn scrape_until_contains(test_server: &TestServer, needle: &str) -> String {
+    for _ in 0..20 {
+        let body = test_server.client.get("/metrics").await.text().await.unwrap();
+
+        if body.contains(needle) {
+            return body;
+        }
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+
+    panic!("Timed out waiting for '{needle}' to appear in /metrics");
+}
+
+#[tokio::test]
+async fn scrape_endpoint_exposes_mcp_tool_call_metrics() {
+    let service_name = unique_service_name("prometheus-mcp-metrics");
+    let config = create_test_config_with_prometheus(&service_name);
+
+    let mut builder = TestServer::builder();
+    let mut service = TestService::streamable_http("test_mcp_server".to_string());
+    service.add_tool(AdderTool);
+    builder.spawn_service(service).await;
+
+    let test_server = builder.build(&config).await;
+    let mcp = test_server.mcp_client("/mcp").await;
+
+    let _results = mcp.search(&["adder"]).await;
+
+    let body = scrape_until_contains(&test_server, "mcp_tool_call_duration").await;
+
+    assert!(body.contains("# TYPE mcp_tool_call_duration"), "body was:\n{body}");
+}
+
+#[tokio::test]
+async fn scrape_endpoint_is_absent_when_disabled() {
+    let service_name = unique_service_name("prometheus-disabled");
+
+    let config = formatdoc! {r#"
+        [server]
+        listen_address = "127.0.0.1:0"
+
+        [telemetry]
+        service_name = "{service_name}"
+
+        [mcp]
+        enabled = true
+        path = "/mcp"
+    "#};
+
+    let test_server = TestServer::builder().build(&config).await;
+
+    let response = test_server.client.get("/metrics").await;
+    assert_eq!(response.status(), reqwest::StatusCode::NOT_FOUND);
+}
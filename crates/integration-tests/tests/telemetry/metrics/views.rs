@@ -0,0 +1,96 @@
+//! Tests for configurable OpenTelemetry metric views
+
+use clickhouse::Row;
+use indoc::formatdoc;
+use integration_tests::{TestServer, TestService, telemetry::*, tools::AdderTool};
+use reqwest::header::HeaderMap;
+use serde::Deserialize;
+
+use crate::telemetry::metrics::HistogramMetricRow;
+
+/// Row capturing the bucket boundaries a histogram was exported with
+#[derive(Row, Deserialize, Debug, Clone)]
+#[serde(rename_all = "PascalCase")]
+struct HistogramBoundsRow {
+    metric_name: String,
+    explicit_bounds: Vec<f64>,
+}
+
+#[tokio::test]
+async fn view_overrides_bucket_boundaries_and_drops_attribute() {
+    let service_name = unique_service_name("metric-views");
+
+    let config = formatdoc! {r#"
+        [telemetry]
+        service_name = "{service_name}"
+
+        [telemetry.exporters.otlp]
+        enabled = true
+        endpoint = "http://localhost:4317"
+        protocol = "grpc"
+
+        [telemetry.exporters.otlp.batch_export]
+        scheduled_delay = "1s"
+        max_export_batch_size = 100
+
+        [[telemetry.metrics.views]]
+        name = "mcp.tool.call.duration"
+        bucket_boundaries = [5.0, 10.0, 25.0, 50.0, 100.0]
+        drop_attributes = ["client.id"]
+
+        [mcp]
+        enabled = true
+        path = "/mcp"
+    "#};
+
+    let mut builder = TestServer::builder();
+    let mut service = TestService::streamable_http("test_mcp_server".to_string());
+    service.add_tool(AdderTool);
+    builder.spawn_service(service).await;
+
+    let test_server = builder.build(&config).await;
+
+    let client_id = format!("test-views-{}", uuid::Uuid::new_v4());
+    let mut headers = HeaderMap::new();
+    headers.insert("x-client-id", client_id.parse().unwrap());
+
+    let mcp = test_server.mcp_client_with_headers("/mcp", headers).await;
+
+    let _result = mcp
+        .execute("test_mcp_server__adder", serde_json::json!({"a": 1, "b": 2}))
+        .await;
+
+    let clickhouse = create_clickhouse_client().await;
+
+    let bounds_query = formatdoc! {r#"
+        SELECT MetricName, ExplicitBounds
+        FROM otel_metrics_histogram
+        WHERE
+            MetricName = 'mcp.tool.call.duration'
+            AND ServiceName = '{service_name}'
+        ORDER BY TimeUnix DESC
+    "#};
+
+    let bounds = wait_for_metrics_matching::<HistogramBoundsRow, _>(&clickhouse, &bounds_query, |rows| !rows.is_empty())
+        .await
+        .expect("Failed to get histogram bounds");
+
+    // Expected: the configured bucket boundaries were applied via the view
+    assert_eq!(bounds[0].explicit_bounds, vec![5.0, 10.0, 25.0, 50.0, 100.0]);
+
+    let attrs_query = formatdoc! {r#"
+        SELECT MetricName, Attributes, Count
+        FROM otel_metrics_histogram
+        WHERE
+            MetricName = 'mcp.tool.call.duration'
+            AND ServiceName = '{service_name}'
+        ORDER BY TimeUnix DESC
+    "#};
+
+    let histograms = wait_for_metrics_matching::<HistogramMetricRow, _>(&clickhouse, &attrs_query, |rows| !rows.is_empty())
+        .await
+        .expect("Failed to get histogram attributes");
+
+    // Expected: the dropped attribute no longer shows up on the exported series
+    assert!(!histograms[0].attributes.iter().any(|(k, _)| k == "client.id"));
+}
@@ -969,3 +969,74 @@ async fn mcp_nested_spans_in_same_trace() {
         );
     }
 }
+
+#[tokio::test]
+async fn mcp_baggage_rides_along_as_span_attributes() {
+    let service_name = unique_service_name("mcp-baggage");
+    let config = create_mcp_tracing_config(&service_name);
+
+    let mut builder = TestServer::builder();
+    let mut service = TestService::streamable_http("test_mcp_server".to_string());
+    service.add_tool(AdderTool);
+    builder.spawn_service(service).await;
+
+    let test_server = builder.build(&config).await;
+
+    // Generate trace context
+    let trace_id = format!("{:032x}", uuid::Uuid::new_v4().as_u128());
+    let span_id = format!("{:016x}", rand::random::<u64>());
+    let traceparent = format!("00-{}-{}-01", trace_id, span_id);
+
+    let mut headers = HeaderMap::new();
+    headers.insert("traceparent", traceparent.parse().unwrap());
+    headers.insert("baggage", "client_id=baggage-client,group=baggage-group".parse().unwrap());
+
+    let mcp = test_server.mcp_client_with_headers("/mcp", headers).await;
+
+    let _tools = mcp.list_tools().await;
+
+    let clickhouse = create_clickhouse_client().await;
+
+    let query = formatdoc! {r#"
+        SELECT
+            TraceId,
+            SpanId,
+            ParentSpanId,
+            SpanName,
+            ServiceName,
+            SpanAttributes,
+            StatusCode
+        FROM otel_traces
+        WHERE
+            ServiceName = '{service_name}'
+            AND TraceId = '{trace_id}'
+            AND SpanName = 'tools/list'
+        ORDER BY Timestamp DESC
+    "#};
+
+    let spans = wait_for_metrics_matching::<TraceSpanRow, _>(&clickhouse, &query, |rows| !rows.is_empty())
+        .await
+        .expect("Failed to get MCP trace spans");
+
+    let mut span = spans.into_iter().next().unwrap();
+
+    // This span is in the same trace as the incoming traceparent, confirming the gateway's
+    // own MCP span shares the client's trace id.
+    assert_eq!(span.trace_id, trace_id);
+
+    span.span_attributes.retain(|(k, _)| k.starts_with("baggage."));
+    span.span_attributes.sort_by(|a, b| a.0.cmp(&b.0));
+
+    insta::assert_json_snapshot!(span.span_attributes, @r#"
+    [
+      [
+        "baggage.client_id",
+        "baggage-client"
+      ],
+      [
+        "baggage.group",
+        "baggage-group"
+      ]
+    ]
+    "#);
+}
@@ -0,0 +1,164 @@
+//! Bearer-token authentication middleware for the `/llm` endpoints.
+//!
+//! Unlike the MCP endpoints' JWKS-backed OAuth2 flow, `[llm.auth]` validates a
+//! self-contained HMAC-signed JWT against the configured shared secret and
+//! required claims, entirely locally, before a request is allowed to reach
+//! provider dispatch.
+
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use axum::body::Body;
+use config::LlmAuthConfig;
+use http::{Request, Response, StatusCode, header::AUTHORIZATION};
+use jwt_compact::{
+    Algorithm, AlgorithmExt, TimeOptions, UntrustedToken,
+    alg::{Hs256, Hs256Key},
+};
+use secrecy::ExposeSecret;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tower::Layer;
+
+const BEARER_TOKEN_LENGTH: usize = 6;
+
+/// Claims carried by an LLM access token. `required_claims` is an arbitrary
+/// string map, so every claim is captured via `additional` rather than a
+/// fixed set of fields.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+struct LlmClaims {
+    #[serde(flatten)]
+    additional: HashMap<String, Value>,
+}
+
+impl LlmClaims {
+    /// Whether every configured required claim is present with the expected value.
+    fn satisfies(&self, required: &std::collections::BTreeMap<String, String>) -> bool {
+        required.iter().all(|(key, expected)| match self.additional.get(key) {
+            Some(Value::String(actual)) => actual == expected,
+            _ => false,
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct LlmAuthLayer {
+    config: Arc<LlmAuthConfig>,
+}
+
+impl LlmAuthLayer {
+    pub fn new(config: LlmAuthConfig) -> Self {
+        Self { config: Arc::new(config) }
+    }
+}
+
+impl<Service> Layer<Service> for LlmAuthLayer
+where
+    Service: Send + Clone,
+{
+    type Service = LlmAuthService<Service>;
+
+    fn layer(&self, next: Service) -> Self::Service {
+        LlmAuthService {
+            next,
+            config: self.config.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct LlmAuthService<Service> {
+    next: Service,
+    config: Arc<LlmAuthConfig>,
+}
+
+impl<Service, ReqBody> tower::Service<Request<ReqBody>> for LlmAuthService<Service>
+where
+    Service: tower::Service<Request<ReqBody>, Response = Response<Body>> + Send + Clone + 'static,
+    Service::Future: Send,
+    Service::Error: Display + 'static,
+    ReqBody: http_body::Body + Send + 'static,
+{
+    type Response = http::Response<Body>;
+    type Error = Service::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Response<Body>, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.next.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<ReqBody>) -> Self::Future {
+        let mut next = self.next.clone();
+        let config = self.config.clone();
+
+        Box::pin(async move {
+            match authenticate(&req, &config) {
+                Ok(()) => next.call(req).await,
+                Err((status, message)) => {
+                    let body = format!(r#"{{"error":"{message}"}}"#);
+
+                    let response = Response::builder()
+                        .status(status)
+                        .header("Content-Type", "application/json")
+                        .body(Body::from(body))
+                        .unwrap();
+
+                    Ok(response)
+                }
+            }
+        })
+    }
+}
+
+/// Validates the request's `Authorization: Bearer <token>` header against `config`:
+/// the token must be a valid, unexpired HS256 JWT signed with `config.secret`, and its
+/// claims must satisfy every entry in `config.required_claims`.
+fn authenticate<B>(req: &Request<B>, config: &LlmAuthConfig) -> Result<(), (StatusCode, &'static str)> {
+    let header = req
+        .headers()
+        .get(AUTHORIZATION)
+        .ok_or((StatusCode::UNAUTHORIZED, "missing bearer token"))?;
+
+    let value = header
+        .to_str()
+        .map_err(|_| (StatusCode::UNAUTHORIZED, "invalid authorization header"))?;
+
+    if value.len() <= BEARER_TOKEN_LENGTH
+        || !value[..BEARER_TOKEN_LENGTH].eq_ignore_ascii_case("bearer")
+        || value.chars().nth(BEARER_TOKEN_LENGTH) != Some(' ')
+    {
+        return Err((StatusCode::UNAUTHORIZED, "token must be prefixed with Bearer"));
+    }
+
+    let token_str = &value[BEARER_TOKEN_LENGTH + 1..];
+
+    if token_str.is_empty() {
+        return Err((StatusCode::UNAUTHORIZED, "missing token"));
+    }
+
+    let untrusted = UntrustedToken::new(token_str).map_err(|_| (StatusCode::UNAUTHORIZED, "invalid token"))?;
+
+    let key = Hs256Key::new(config.secret.expose_secret().as_bytes());
+
+    let token = Hs256
+        .validator::<LlmClaims>(&key)
+        .validate(&untrusted)
+        .map_err(|_| (StatusCode::UNAUTHORIZED, "invalid token"))?;
+
+    token
+        .claims()
+        .validate_expiration(&TimeOptions::default())
+        .map_err(|_| (StatusCode::UNAUTHORIZED, "token expired"))?;
+
+    if !token.claims().custom.satisfies(&config.required_claims) {
+        return Err((StatusCode::FORBIDDEN, "token missing required claims"));
+    }
+
+    Ok(())
+}
@@ -37,10 +37,18 @@ pub enum LlmError {
     #[error("Insufficient quota: {0}")]
     InsufficientQuota(String),
 
+    /// Caller's country or group is not permitted to use the requested model or provider.
+    #[error("Access denied: {0}")]
+    AccessDenied(String),
+
     /// Streaming not supported.
     #[error("Streaming is not yet supported. Please set stream=false or omit the parameter.")]
     StreamingNotSupported,
 
+    /// Fill-in-the-middle completion not supported by the provider or the requested model.
+    #[error("Fill-in-the-middle completion is not supported by this provider or model.")]
+    FimNotSupported,
+
     /// Provider API returned an error.
     #[error("Provider API error ({status}): {message}")]
     ProviderApiError { status: u16, message: String },
@@ -60,10 +68,12 @@ impl LlmError {
     /// Get the appropriate HTTP status code for this error.
     pub fn status_code(&self) -> StatusCode {
         match self {
-            Self::InvalidModelFormat(_) | Self::InvalidRequest(_) | Self::StreamingNotSupported => {
-                StatusCode::BAD_REQUEST
-            }
+            Self::InvalidModelFormat(_)
+            | Self::InvalidRequest(_)
+            | Self::StreamingNotSupported
+            | Self::FimNotSupported => StatusCode::BAD_REQUEST,
             Self::AuthenticationFailed(_) => StatusCode::UNAUTHORIZED,
+            Self::AccessDenied(_) => StatusCode::FORBIDDEN,
             Self::InsufficientQuota(_) => StatusCode::FORBIDDEN,
             Self::ProviderNotFound(_) | Self::ModelNotFound(_) => StatusCode::NOT_FOUND,
             Self::RateLimitExceeded { .. } => StatusCode::TOO_MANY_REQUESTS,
@@ -87,10 +97,12 @@ impl LlmError {
     /// Get the error type string for the response.
     pub fn error_type(&self) -> &str {
         match self {
-            Self::InvalidModelFormat(_) | Self::InvalidRequest(_) | Self::StreamingNotSupported => {
-                "invalid_request_error"
-            }
+            Self::InvalidModelFormat(_)
+            | Self::InvalidRequest(_)
+            | Self::StreamingNotSupported
+            | Self::FimNotSupported => "invalid_request_error",
             Self::AuthenticationFailed(_) => "authentication_error",
+            Self::AccessDenied(_) => "access_denied",
             Self::InsufficientQuota(_) => "insufficient_quota",
             Self::ProviderNotFound(_) | Self::ModelNotFound(_) => "not_found_error",
             Self::RateLimitExceeded { .. } => "rate_limit_error",
@@ -8,8 +8,9 @@ use axum::{
     routing::{get, post},
 };
 use futures::StreamExt;
-use messages::ChatCompletionRequest;
+use messages::{ChatCompletionRequest, FimCompletionRequest};
 
+mod auth;
 mod error;
 mod messages;
 pub mod provider;
@@ -17,6 +18,7 @@ mod request;
 mod server;
 pub mod token_counter;
 
+use auth::LlmAuthLayer;
 pub use error::LlmError;
 use server::{LlmHandler, LlmServerBuilder};
 
@@ -31,11 +33,16 @@ pub async fn router(config: &config::Config) -> anyhow::Result<Router> {
             .map_err(|e| anyhow::anyhow!("Failed to initialize LLM server: {e}"))?,
     );
 
-    let ai_routes = Router::new()
+    let mut ai_routes = Router::new()
         .route("/v1/chat/completions", post(chat_completions))
+        .route("/v1/fim/completions", post(fim_completions))
         .route("/v1/models", get(list_models))
         .with_state(server);
 
+    if let Some(auth_config) = config.llm.auth.clone() {
+        ai_routes = ai_routes.layer(LlmAuthLayer::new(auth_config));
+    }
+
     Ok(Router::new().nest(&config.llm.path, ai_routes))
 }
 
@@ -55,7 +62,11 @@ async fn chat_completions(
     log::debug!("Streaming: {}", request.stream.unwrap_or(false));
 
     // Extract request context including client identity
-    let context = request::extract_context(&headers, client_identity.as_ref().map(|ext| &ext.0));
+    let context = request::extract_context(
+        &headers,
+        client_identity.as_ref().map(|ext| &ext.0),
+        server.country_header(),
+    );
 
     if let Some(ref client_id) = context.client_id {
         log::debug!(
@@ -109,6 +120,34 @@ async fn chat_completions(
     }
 }
 
+/// Handle fill-in-the-middle completion requests.
+///
+/// Only models explicitly configured with `fim = true` accept this request; all
+/// other models return a 400 error.
+async fn fim_completions(
+    State(server): State<Arc<LlmHandler>>,
+    headers: HeaderMap,
+    client_identity: Option<Extension<config::ClientIdentity>>,
+    Json(request): Json<FimCompletionRequest>,
+) -> Result<impl IntoResponse> {
+    log::info!("LLM FIM completions handler called for model: {}", request.model);
+
+    let context = request::extract_context(
+        &headers,
+        client_identity.as_ref().map(|ext| &ext.0),
+        server.country_header(),
+    );
+
+    let response = server.fim_completion(request, &context).await?;
+
+    log::debug!(
+        "FIM completion successful, returning response with {} choices",
+        response.choices.len()
+    );
+
+    Ok(Json(response).into_response())
+}
+
 /// Handle list models requests.
 async fn list_models(State(server): State<Arc<LlmHandler>>) -> Result<impl IntoResponse> {
     let response = server.models();
@@ -7,6 +7,19 @@ pub(crate) struct ChatCompletionRequest {
     pub(crate) model: String,
 }
 
+/// Mistral-style fill-in-the-middle completion request: given a `prompt` (code before the
+/// cursor) and an optional `suffix` (code after the cursor), the model generates the
+/// infill. Only models configured with `fim = true` accept this request shape.
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct FimCompletionRequest {
+    pub(crate) model: String,
+    pub(crate) prompt: String,
+    pub(crate) suffix: Option<String>,
+    pub(crate) max_tokens: Option<u32>,
+    pub(crate) temperature: Option<f32>,
+    pub(crate) stream: Option<bool>,
+}
+
 /// Chat message in OpenAI format.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub(crate) struct ChatMessage {
@@ -1,4 +1,5 @@
 pub(crate) mod anthropic;
+pub(crate) mod azure;
 pub mod bedrock;
 pub(crate) mod google;
 mod model_manager;
@@ -12,11 +13,12 @@ use futures::Stream;
 use std::pin::Pin;
 
 use crate::{
-    messages::{ChatCompletionChunk, ChatCompletionRequest, ChatCompletionResponse, Model},
+    error::LlmError,
+    messages::{ChatCompletionChunk, ChatCompletionRequest, ChatCompletionResponse, FimCompletionRequest, Model},
     request::RequestContext,
 };
-use config::{HeaderRule, ModelConfig};
-use reqwest::{Client, Method, RequestBuilder};
+use config::{HeaderRule, ModelConfig, TlsClientConfig};
+use reqwest::{Client, ClientBuilder, Method, RequestBuilder};
 
 /// Type alias for a stream of chat completion chunks.
 ///
@@ -25,6 +27,23 @@ use reqwest::{Client, Method, RequestBuilder};
 /// to allow for dynamic dispatch across different provider implementations.
 pub(crate) type ChatCompletionStream = Pin<Box<dyn Stream<Item = crate::Result<ChatCompletionChunk>> + Send>>;
 
+/// Apply client TLS settings (custom root CA, client certificate for mutual TLS, and
+/// certificate/hostname verification toggles) to a [`reqwest::ClientBuilder`].
+///
+/// Delegates to `config::apply_to_reqwest_builder`, shared with the `mcp` crate's
+/// downstream HTTP client, since both present a client identity to an upstream HTTP server
+/// over TLS.
+pub(crate) fn apply_tls(builder: ClientBuilder, tls: Option<&TlsClientConfig>) -> crate::Result<ClientBuilder> {
+    let Some(tls) = tls else {
+        return Ok(builder);
+    };
+
+    config::apply_to_reqwest_builder(builder, tls).map_err(|e| {
+        log::error!("Failed to apply TLS configuration: {e}");
+        LlmError::InternalError(None)
+    })
+}
+
 /// Trait for LLM provider implementations.
 ///
 /// Note for async_trait: We need this trait to be dyn-compatible, so we can't just use the
@@ -66,6 +85,20 @@ pub(crate) trait Provider: Send + Sync {
         false
     }
 
+    /// Process a fill-in-the-middle completion request.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LlmError::FimNotSupported` if the provider doesn't support FIM completion.
+    async fn fim_completion(
+        &self,
+        _request: FimCompletionRequest,
+        _context: &RequestContext,
+    ) -> crate::Result<ChatCompletionResponse> {
+        // Default implementation returns an error for providers that don't support FIM
+        Err(crate::error::LlmError::FimNotSupported)
+    }
+
     /// List available models for this provider.
     fn list_models(&self) -> Vec<Model>;
 
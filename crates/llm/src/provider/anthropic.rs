@@ -17,7 +17,7 @@ use self::{
 use crate::{
     error::LlmError,
     messages::{ChatCompletionRequest, ChatCompletionResponse, Model},
-    provider::{ChatCompletionStream, HttpProvider, ModelManager, Provider, token},
+    provider::{ChatCompletionStream, HttpProvider, ModelManager, Provider, apply_tls, token},
     request::RequestContext,
 };
 use config::HeaderRule;
@@ -53,14 +53,17 @@ impl AnthropicProvider {
             })?,
         );
 
-        let client = Client::builder()
-            .timeout(std::time::Duration::from_secs(60))
-            .default_headers(headers)
-            .build()
-            .map_err(|e| {
-                log::error!("Failed to create HTTP client for Anthropic provider: {e}");
-                LlmError::InternalError(None)
-            })?;
+        let client_builder = apply_tls(
+            Client::builder()
+                .timeout(std::time::Duration::from_secs(60))
+                .default_headers(headers),
+            config.tls.as_ref(),
+        )?;
+
+        let client = client_builder.build().map_err(|e| {
+            log::error!("Failed to create HTTP client for Anthropic provider: {e}");
+            LlmError::InternalError(None)
+        })?;
 
         let base_url = config
             .base_url
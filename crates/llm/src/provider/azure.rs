@@ -0,0 +1,186 @@
+pub(super) mod input;
+pub(super) mod output;
+
+use async_trait::async_trait;
+use axum::http::HeaderMap;
+use config::AzureProviderConfig;
+use reqwest::{Client, Method};
+use secrecy::ExposeSecret;
+
+use self::{input::AzureRequest, output::AzureResponse};
+
+use crate::{
+    error::LlmError,
+    messages::{ChatCompletionRequest, ChatCompletionResponse, Model},
+    provider::{ChatCompletionStream, HttpProvider, ModelManager, Provider},
+    request::RequestContext,
+};
+use config::HeaderRule;
+
+/// Azure OpenAI provider.
+///
+/// Unlike the other HTTP providers, Azure has no single `base_url` - each model is routed
+/// to its own deployment, so the request URL is built per-call from
+/// [`AzureProviderConfig::resource_name`], the resolved model's `deployment_id`, and
+/// [`AzureProviderConfig::api_version`]. Authentication uses the `api-key` header rather
+/// than `Authorization: Bearer`.
+pub(crate) struct AzureProvider {
+    client: Client,
+    resource_name: String,
+    api_version: String,
+    api_key: secrecy::SecretString,
+    name: String,
+    config: AzureProviderConfig,
+    model_manager: ModelManager,
+}
+
+impl AzureProvider {
+    pub fn new(name: String, config: AzureProviderConfig) -> crate::Result<Self> {
+        let mut headers = HeaderMap::new();
+
+        headers.insert(
+            "content-type",
+            "application/json".parse().map_err(|e| {
+                log::error!("Failed to parse content-type header for Azure provider: {e}");
+                LlmError::InternalError(None)
+            })?,
+        );
+
+        let client = Client::builder()
+            .timeout(std::time::Duration::from_secs(60))
+            .default_headers(headers)
+            .build()
+            .map_err(|e| {
+                log::error!("Failed to create HTTP client for Azure provider: {e}");
+                LlmError::InternalError(None)
+            })?;
+
+        // Convert ApiModelConfig to unified ModelConfig for ModelManager
+        let models = config
+            .models
+            .clone()
+            .into_iter()
+            .map(|(k, v)| (k, config::ModelConfig::Api(v)))
+            .collect();
+        let model_manager = ModelManager::new(models, "azure");
+
+        let api_key = config.api_key.clone();
+        let resource_name = config.resource_name.clone();
+        let api_version = config.api_version.clone();
+
+        Ok(Self {
+            client,
+            resource_name,
+            api_version,
+            api_key,
+            name,
+            model_manager,
+            config,
+        })
+    }
+
+    /// Build the deployment URL for a resolved model, e.g.
+    /// `https://{resource_name}.openai.azure.com/openai/deployments/{deployment_id}/chat/completions?api-version={api_version}`.
+    fn deployment_url(&self, deployment_id: &str) -> String {
+        format!(
+            "https://{}.openai.azure.com/openai/deployments/{}/chat/completions?api-version={}",
+            self.resource_name, deployment_id, self.api_version
+        )
+    }
+}
+
+#[async_trait]
+impl Provider for AzureProvider {
+    async fn chat_completion(
+        &self,
+        mut request: ChatCompletionRequest,
+        context: &RequestContext,
+    ) -> crate::Result<ChatCompletionResponse> {
+        let original_model = request.model.clone();
+
+        // Check if the model is configured and get the actual model name to use
+        let actual_model = self
+            .model_manager
+            .resolve_model(&request.model)
+            .ok_or_else(|| LlmError::ModelNotFound(format!("Model '{}' is not configured", request.model)))?;
+
+        // Get the model config to access headers and the deployment id
+        let model_config = self.model_manager.get_model_config(&request.model);
+
+        let deployment_id = model_config
+            .and_then(|c| c.deployment_id())
+            .ok_or_else(|| LlmError::ModelNotFound(format!("Model '{original_model}' has no deployment_id configured")))?;
+
+        let url = self.deployment_url(deployment_id);
+
+        request.model = actual_model;
+        let azure_request = AzureRequest::from(request);
+
+        let mut request_builder = self.request_builder(Method::POST, &url, context, model_config);
+        request_builder = request_builder.header("api-key", self.api_key.expose_secret());
+
+        let response = request_builder
+            .json(&azure_request)
+            .send()
+            .await
+            .map_err(|e| LlmError::ConnectionError(format!("Failed to send request to Azure OpenAI: {e}")))?;
+
+        let status = response.status();
+
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+            log::error!("Azure OpenAI API error ({status}): {error_text}");
+
+            return Err(match status.as_u16() {
+                401 => LlmError::AuthenticationFailed(error_text),
+                403 => LlmError::InsufficientQuota(error_text),
+                404 => LlmError::ModelNotFound(error_text),
+                429 => LlmError::RateLimitExceeded { message: error_text },
+                400 => LlmError::InvalidRequest(error_text),
+                500 => LlmError::InternalError(Some(error_text)),
+                _ => LlmError::ProviderApiError {
+                    status: status.as_u16(),
+                    message: error_text,
+                },
+            });
+        }
+
+        let response_text = response.text().await.map_err(|e| {
+            log::error!("Failed to read Azure OpenAI response body: {e}");
+            LlmError::InternalError(None)
+        })?;
+
+        let azure_response: AzureResponse = sonic_rs::from_str(&response_text).map_err(|e| {
+            log::error!("Failed to parse Azure OpenAI chat completion response: {e}");
+            log::error!("Raw response that failed to parse: {response_text}");
+            LlmError::InternalError(None)
+        })?;
+
+        let mut response = ChatCompletionResponse::from(azure_response);
+        response.model = original_model;
+
+        Ok(response)
+    }
+
+    fn list_models(&self) -> Vec<Model> {
+        self.model_manager.get_configured_models()
+    }
+
+    fn supports_streaming(&self) -> bool {
+        false
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl HttpProvider for AzureProvider {
+    fn get_provider_headers(&self) -> &[HeaderRule] {
+        &self.config.headers
+    }
+
+    fn get_http_client(&self) -> &Client {
+        &self.client
+    }
+}
@@ -0,0 +1,80 @@
+use serde::Serialize;
+
+use crate::messages::{ChatCompletionRequest, ChatMessage, Tool, ToolChoice};
+
+/// Request body for the Azure OpenAI Chat Completions API.
+///
+/// Azure exposes the same wire format as OpenAI's `/chat/completions` endpoint, with the
+/// model selection happening through the deployment segment of the URL rather than the
+/// `model` field - Azure ignores this field but we keep it for compatibility with clients
+/// that expect to see it echoed back.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(super) struct AzureRequest {
+    pub(super) model: String,
+
+    pub(super) messages: Vec<ChatMessage>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) temperature: Option<f32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) max_completion_tokens: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) top_p: Option<f32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) frequency_penalty: Option<f32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) presence_penalty: Option<f32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) stop: Option<Vec<String>>,
+
+    pub(super) stream: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) tools: Option<Vec<Tool>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) tool_choice: Option<ToolChoice>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) parallel_tool_calls: Option<bool>,
+}
+
+impl From<ChatCompletionRequest> for AzureRequest {
+    fn from(request: ChatCompletionRequest) -> Self {
+        let ChatCompletionRequest {
+            model,
+            messages,
+            temperature,
+            max_tokens,
+            top_p,
+            frequency_penalty,
+            presence_penalty,
+            stop,
+            stream,
+            tools,
+            tool_choice,
+            parallel_tool_calls,
+        } = request;
+
+        Self {
+            model,
+            messages,
+            temperature,
+            max_completion_tokens: max_tokens,
+            top_p,
+            frequency_penalty,
+            presence_penalty,
+            stop,
+            stream: stream.unwrap_or(false),
+            tools,
+            tool_choice,
+            parallel_tool_calls,
+        }
+    }
+}
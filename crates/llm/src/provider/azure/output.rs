@@ -0,0 +1,85 @@
+use serde::Deserialize;
+
+use crate::messages::{ChatChoice, ChatCompletionResponse, ChatMessage, FinishReason, ObjectType, Usage};
+
+/// Response from the Azure OpenAI Chat Completions API.
+///
+/// Azure mirrors OpenAI's response shape exactly, since both sit behind the same
+/// `/chat/completions` wire format.
+#[derive(Debug, Deserialize)]
+pub(super) struct AzureResponse {
+    pub id: String,
+
+    #[allow(dead_code)]
+    pub created: u64,
+
+    pub choices: Vec<AzureChoice>,
+
+    pub usage: AzureUsage,
+}
+
+/// A single completion choice in an Azure OpenAI response.
+#[derive(Debug, Deserialize)]
+pub(super) struct AzureChoice {
+    pub index: u32,
+
+    pub message: AzureMessage,
+
+    #[serde(default)]
+    pub finish_reason: Option<String>,
+}
+
+/// A message returned by the Azure OpenAI API.
+#[derive(Debug, Deserialize)]
+pub(super) struct AzureMessage {
+    pub role: String,
+
+    #[serde(default)]
+    pub content: String,
+}
+
+/// Token usage information for an Azure OpenAI request.
+#[derive(Debug, Deserialize)]
+pub(super) struct AzureUsage {
+    pub prompt_tokens: u32,
+    pub completion_tokens: u32,
+    pub total_tokens: u32,
+}
+
+impl From<AzureResponse> for ChatCompletionResponse {
+    fn from(response: AzureResponse) -> Self {
+        Self {
+            id: response.id,
+            object: ObjectType::ChatCompletion,
+            created: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            model: String::new(), // Will be set by the provider
+            choices: response
+                .choices
+                .into_iter()
+                .map(|choice| ChatChoice {
+                    index: choice.index,
+                    message: ChatMessage {
+                        role: choice.message.role,
+                        content: choice.message.content,
+                    },
+                    finish_reason: match choice.finish_reason.as_deref() {
+                        Some("stop") => FinishReason::Stop,
+                        Some("length") => FinishReason::Length,
+                        Some("tool_calls") => FinishReason::ToolCalls,
+                        Some("content_filter") => FinishReason::ContentFilter,
+                        Some(other) => FinishReason::Other(other.to_string()),
+                        None => FinishReason::Stop,
+                    },
+                })
+                .collect(),
+            usage: Usage {
+                prompt_tokens: response.usage.prompt_tokens,
+                completion_tokens: response.usage.completion_tokens,
+                total_tokens: response.usage.total_tokens,
+            },
+        }
+    }
+}
@@ -2,6 +2,12 @@
 //!
 //! This module provides integration with AWS Bedrock foundation models through
 //! the Converse API, which provides a unified interface across all model families.
+//!
+//! Request signing (SigV4) is handled internally by `aws-sdk-bedrockruntime` using
+//! the credentials resolved in [`BedrockProvider::new`] - we deliberately don't hand-roll
+//! canonical request construction or the `AWS4-HMAC-SHA256` signing steps ourselves,
+//! since the SDK already implements them against the same `access_key_id`/`secret_access_key`/
+//! `region` fields on [`BedrockProviderConfig`].
 
 mod input;
 mod output;
@@ -17,7 +17,7 @@ use futures::StreamExt;
 use crate::{
     error::LlmError,
     messages::{ChatCompletionRequest, ChatCompletionResponse, Model},
-    provider::{HttpProvider, ModelManager, Provider, openai::extract_model_from_full_name, token},
+    provider::{HttpProvider, ModelManager, Provider, apply_tls, openai::extract_model_from_full_name, token},
     request::RequestContext,
 };
 use config::HeaderRule;
@@ -34,13 +34,15 @@ pub(crate) struct GoogleProvider {
 
 impl GoogleProvider {
     pub fn new(name: String, config: ApiProviderConfig) -> crate::Result<Self> {
-        let client = Client::builder()
-            .timeout(std::time::Duration::from_secs(60))
-            .build()
-            .map_err(|e| {
-                log::error!("Failed to create HTTP client for Google provider: {e}");
-                LlmError::InternalError(None)
-            })?;
+        let client_builder = apply_tls(
+            Client::builder().timeout(std::time::Duration::from_secs(60)),
+            config.tls.as_ref(),
+        )?;
+
+        let client = client_builder.build().map_err(|e| {
+            log::error!("Failed to create HTTP client for Google provider: {e}");
+            LlmError::InternalError(None)
+        })?;
 
         let base_url = config
             .base_url
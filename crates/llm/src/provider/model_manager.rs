@@ -83,6 +83,12 @@ mod tests {
                 rename: None,
                 rate_limits: None,
                 headers: Vec::new(),
+                fim: false,
+                access: None,
+                max_tokens: None,
+                context_window: None,
+                deployment_id: None,
+                modality: config::Modality::Text,
             }),
         );
 
@@ -101,6 +107,12 @@ mod tests {
                 rename: Some("claude-3-opus-20240229".to_string()),
                 rate_limits: None,
                 headers: Vec::new(),
+                fim: false,
+                access: None,
+                max_tokens: None,
+                context_window: None,
+                deployment_id: None,
+                modality: config::Modality::Text,
             }),
         );
 
@@ -122,6 +134,12 @@ mod tests {
                 rename: None,
                 rate_limits: None,
                 headers: Vec::new(),
+                fim: false,
+                access: None,
+                max_tokens: None,
+                context_window: None,
+                deployment_id: None,
+                modality: config::Modality::Text,
             }),
         );
         models.insert(
@@ -130,6 +148,12 @@ mod tests {
                 rename: None,
                 rate_limits: None,
                 headers: Vec::new(),
+                fim: false,
+                access: None,
+                max_tokens: None,
+                context_window: None,
+                deployment_id: None,
+                modality: config::Modality::Text,
             }),
         );
 
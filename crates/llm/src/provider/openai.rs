@@ -10,14 +10,14 @@ use reqwest::{Client, header::AUTHORIZATION};
 use secrecy::ExposeSecret;
 
 use self::{
-    input::OpenAIRequest,
+    input::{FimRequest, OpenAIRequest},
     output::{OpenAIResponse, OpenAIStreamChunk},
 };
 
 use crate::{
     error::LlmError,
-    messages::{ChatCompletionRequest, ChatCompletionResponse, Model},
-    provider::{ChatCompletionStream, ModelManager, Provider, token},
+    messages::{ChatCompletionRequest, ChatCompletionResponse, FimCompletionRequest, Model},
+    provider::{ChatCompletionStream, ModelManager, Provider, apply_tls, token},
     request::RequestContext,
 };
 
@@ -35,14 +35,17 @@ impl OpenAIProvider {
     pub fn new(name: String, config: LlmProviderConfig) -> crate::Result<Self> {
         let headers = HeaderMap::new();
 
-        let client = Client::builder()
-            .timeout(std::time::Duration::from_secs(60))
-            .default_headers(headers)
-            .build()
-            .map_err(|e| {
-                log::error!("Failed to create HTTP client for OpenAI provider: {e}");
-                LlmError::InternalError(None)
-            })?;
+        let client_builder = apply_tls(
+            Client::builder()
+                .timeout(std::time::Duration::from_secs(60))
+                .default_headers(headers),
+            config.tls.as_ref(),
+        )?;
+
+        let client = client_builder.build().map_err(|e| {
+            log::error!("Failed to create HTTP client for OpenAI provider: {e}");
+            LlmError::InternalError(None)
+        })?;
 
         // Use custom base URL if provided, otherwise use default
         let base_url = config
@@ -223,6 +226,81 @@ impl Provider for OpenAIProvider {
         Ok(Box::pin(chunk_stream))
     }
 
+    async fn fim_completion(
+        &self,
+        request: FimCompletionRequest,
+        context: &RequestContext,
+    ) -> crate::Result<ChatCompletionResponse> {
+        let model_name = extract_model_from_full_name(&request.model);
+
+        let supports_fim = self
+            .model_manager
+            .get_model_config(&model_name)
+            .map(|config| config.fim())
+            .unwrap_or(false);
+
+        if !supports_fim {
+            return Err(LlmError::FimNotSupported);
+        }
+
+        let url = format!("{}/fim/completions", self.base_url);
+
+        let actual_model = self
+            .model_manager
+            .resolve_model(&model_name)
+            .ok_or_else(|| LlmError::ModelNotFound(format!("Model '{}' is not configured", model_name)))?;
+
+        let original_model = request.model.clone();
+        let mut fim_request = FimRequest::from(request);
+        fim_request.model = actual_model;
+        fim_request.stream = false; // Always false for now
+
+        let mut request_builder = self.client.post(&url);
+        let key = token::get(self.config.forward_token, &self.config.api_key, context)?;
+        request_builder = request_builder.header(AUTHORIZATION, format!("Bearer {}", key.expose_secret()));
+
+        let response = request_builder
+            .json(&fim_request)
+            .send()
+            .await
+            .map_err(|e| LlmError::ConnectionError(format!("Failed to send FIM request: {e}")))?;
+
+        let status = response.status();
+
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+            log::error!("FIM completion API error ({status}): {error_text}");
+
+            return Err(match status.as_u16() {
+                401 => LlmError::AuthenticationFailed(error_text),
+                403 => LlmError::InsufficientQuota(error_text),
+                404 => LlmError::ModelNotFound(error_text),
+                429 => LlmError::RateLimitExceeded(error_text),
+                400 => LlmError::InvalidRequest(error_text),
+                500 => LlmError::InternalError(Some(error_text)),
+                _ => LlmError::ProviderApiError {
+                    status: status.as_u16(),
+                    message: error_text,
+                },
+            });
+        }
+
+        let response_text = response.text().await.map_err(|e| {
+            log::error!("Failed to read FIM response body: {e}");
+            LlmError::InternalError(None)
+        })?;
+
+        let openai_response: OpenAIResponse = sonic_rs::from_str(&response_text).map_err(|e| {
+            log::error!("Failed to parse FIM completion response: {e}");
+            log::error!("Raw response that failed to parse: {response_text}");
+            LlmError::InternalError(None)
+        })?;
+
+        let mut response = ChatCompletionResponse::from(openai_response);
+        response.model = original_model;
+        Ok(response)
+    }
+
     fn supports_streaming(&self) -> bool {
         true
     }
@@ -1,6 +1,6 @@
 use serde::Serialize;
 
-use crate::messages::{ChatCompletionRequest, ChatMessage, Tool, ToolChoice};
+use crate::messages::{ChatCompletionRequest, ChatMessage, FimCompletionRequest, Tool, ToolChoice};
 
 /// Request body for OpenAI Chat Completions API.
 ///
@@ -113,3 +113,43 @@ impl From<ChatCompletionRequest> for OpenAIRequest {
         }
     }
 }
+
+/// Request body for Mistral's fill-in-the-middle `/v1/fim/completions` endpoint.
+///
+/// Unlike chat completions, FIM takes a `prompt` (code before the cursor) and an
+/// optional `suffix` (code after the cursor) rather than a message list.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(super) struct FimRequest {
+    pub(super) model: String,
+    pub(super) prompt: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) suffix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) max_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) temperature: Option<f32>,
+    pub(super) stream: bool,
+}
+
+impl From<FimCompletionRequest> for FimRequest {
+    fn from(request: FimCompletionRequest) -> Self {
+        let FimCompletionRequest {
+            model,
+            prompt,
+            suffix,
+            max_tokens,
+            temperature,
+            stream,
+        } = request;
+
+        Self {
+            model,
+            prompt,
+            suffix,
+            max_tokens,
+            temperature,
+            stream: stream.unwrap_or(false),
+        }
+    }
+}
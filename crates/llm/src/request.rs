@@ -20,13 +20,20 @@ pub(crate) struct RequestContext {
 
     /// Group identifier for hierarchical rate limiting.
     pub group: Option<String>,
+
+    /// Caller's ISO country code, read from the header configured by `llm.country_header`.
+    pub country: Option<String>,
 }
 
 /// Extract request context from request headers and client identity.
 ///
 /// Combines runtime information from headers (like BYOK API keys) with
 /// client identity information for rate limiting and access control.
-pub(super) fn extract_context(headers: &HeaderMap, client_identity: Option<&config::ClientIdentity>) -> RequestContext {
+pub(super) fn extract_context(
+    headers: &HeaderMap,
+    client_identity: Option<&config::ClientIdentity>,
+    country_header: &str,
+) -> RequestContext {
     // Check for BYOK header
     let api_key = headers
         .get(PROVIDER_API_KEY_HEADER)
@@ -40,9 +47,15 @@ pub(super) fn extract_context(headers: &HeaderMap, client_identity: Option<&conf
         (None, None)
     };
 
+    let country = headers
+        .get(country_header)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.to_string());
+
     RequestContext {
         api_key,
         client_id,
         group,
+        country,
     }
 }
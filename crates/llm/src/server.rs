@@ -16,7 +16,7 @@ use rate_limit::{TokenRateLimitManager, TokenRateLimitRequest};
 
 use crate::{
     error::LlmError,
-    messages::{ChatCompletionRequest, ChatCompletionResponse, Model, ModelsResponse, ObjectType},
+    messages::{ChatCompletionRequest, ChatCompletionResponse, FimCompletionRequest, Model, ModelsResponse, ObjectType},
     provider::{ChatCompletionStream, Provider},
     request::RequestContext,
 };
@@ -41,10 +41,15 @@ impl LlmServer {
     /// Check rate limits and return an error if exceeded.
     async fn check_and_enforce_rate_limit(
         &self,
-        request: &ChatCompletionRequest,
+        provider_name: &str,
+        model_name: &str,
+        input_tokens: usize,
         context: &RequestContext,
     ) -> crate::Result<()> {
-        if let Some(wait_duration) = self.check_token_rate_limit(request, context).await {
+        if let Some(wait_duration) = self
+            .check_token_rate_limit(provider_name, model_name, input_tokens, context)
+            .await?
+        {
             // Duration::MAX is used as a sentinel value to indicate the request can never succeed
             // (requires more tokens than the rate limit allows)
             if wait_duration == std::time::Duration::MAX {
@@ -64,6 +69,115 @@ impl LlmServer {
         Ok(())
     }
 
+    /// Check geographic access control and return a 403 error if the caller's country
+    /// is not permitted to use the requested model.
+    ///
+    /// Deny takes precedence over allow, and a model-level rule fully overrides the
+    /// provider-level one when the model configures any country restriction at all.
+    fn check_country_access(
+        &self,
+        provider_name: &str,
+        model_name: &str,
+        context: &RequestContext,
+    ) -> crate::Result<()> {
+        let Some(ref country) = context.country else {
+            // No country header present - nothing to enforce against.
+            return Ok(());
+        };
+
+        let Some(provider_config) = self.shared.config.providers.get(provider_name) else {
+            return Ok(());
+        };
+
+        let provider_access = provider_config.access();
+        let models = provider_config.models();
+        let model_access = models.get(model_name).and_then(|m| m.access());
+
+        if !config::is_country_allowed(provider_access, model_access, country) {
+            return Err(LlmError::AccessDenied(format!(
+                "Model '{provider_name}/{model_name}' is not available in your region"
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Check per-model group access control and return a 403 error if the caller's
+    /// group isn't permitted to use the requested model.
+    ///
+    /// A model in closed beta, or one that configures an explicit `allowed_groups` list,
+    /// is only usable by callers in one of those groups; a caller with no group is denied.
+    fn check_group_access(
+        &self,
+        provider_name: &str,
+        model_name: &str,
+        context: &RequestContext,
+    ) -> crate::Result<()> {
+        let Some(provider_config) = self.shared.config.providers.get(provider_name) else {
+            return Ok(());
+        };
+
+        let models = provider_config.models();
+        let model_access = models.get(model_name).and_then(|m| m.access());
+
+        if !config::is_group_allowed(model_access, context.group.as_deref()) {
+            return Err(LlmError::AccessDenied(format!(
+                "Model '{provider_name}/{model_name}' is not available to your group"
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// HTTP header consulted for the caller's country when evaluating geographic access control.
+    pub fn country_header(&self) -> &str {
+        &self.shared.config.country_header
+    }
+
+    /// Reject requests that the upstream would reject anyway for exceeding the model's
+    /// advertised context window or output token limit, instead of forwarding them.
+    ///
+    /// Uses [`ModelConfig::max_tokens`] as the cap on the request's requested output tokens
+    /// and [`ModelConfig::context_window`] as the cap on input + requested output tokens
+    /// combined. Either check is skipped when the model doesn't advertise the corresponding
+    /// limit.
+    fn check_model_limits(
+        &self,
+        provider_name: &str,
+        model_name: &str,
+        requested_output_tokens: usize,
+        input_tokens: usize,
+    ) -> crate::Result<()> {
+        let Some(provider_config) = self.shared.config.providers.get(provider_name) else {
+            return Ok(());
+        };
+
+        let models = provider_config.models();
+        let Some(model_config) = models.get(model_name) else {
+            return Ok(());
+        };
+
+        if let Some(max_output_tokens) = model_config.max_tokens()
+            && requested_output_tokens > max_output_tokens as usize
+        {
+            return Err(LlmError::InvalidRequest(format!(
+                "Requested max_tokens ({requested_output_tokens}) exceeds the {max_output_tokens} token output limit for model '{provider_name}/{model_name}'"
+            )));
+        }
+
+        if let Some(context_window) = model_config.context_window() {
+            let estimated_total = input_tokens + requested_output_tokens;
+
+            if estimated_total > context_window as usize {
+                return Err(LlmError::InvalidRequest(format!(
+                    "Estimated request size ({estimated_total} tokens) exceeds the {context_window} token context window for model '{provider_name}/{model_name}'"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
     /// List all available models from all providers.
     pub fn models(&self) -> ModelsResponse {
         let models: Vec<Model> = self
@@ -88,36 +202,39 @@ impl LlmServer {
     /// Check token rate limits for a request.
     ///
     /// Returns the duration to wait before retrying if rate limited, or None if the request can proceed.
+    /// A storage-level failure from the rate limiter is propagated as an error rather than
+    /// silently allowing the request, so `[server.rate_limits].on_storage_error` (fail-closed by
+    /// default) is actually honored here instead of always failing open.
     pub async fn check_token_rate_limit(
         &self,
-        request: &ChatCompletionRequest,
+        provider_name: &str,
+        model_name: &str,
+        input_tokens: usize,
         context: &RequestContext,
-    ) -> Option<std::time::Duration> {
+    ) -> crate::Result<Option<std::time::Duration>> {
         // Check if client identification is available
         let Some(ref client_id) = context.client_id else {
             log::debug!(
                 "No client_id found in request context. \
                 Token rate limiting requires client identification to be enabled and a client_id to be present."
             );
-            return None;
+            return Ok(None);
         };
 
         log::debug!(
-            "Checking token rate limit for client_id={client_id}, group={:?}, model={}",
+            "Checking token rate limit for client_id={client_id}, group={:?}, provider={provider_name}, model={model_name}",
             context.group,
-            request.model
         );
 
-        // Extract provider and model from the request
-        let (provider_name, model_name) = request.model.split_once('/')?;
-        log::debug!("Parsed model: provider={}, model={}", provider_name, model_name);
-
         // Get provider config
-        let provider_config = self.shared.config.providers.get(provider_name)?;
+        let Some(provider_config) = self.shared.config.providers.get(provider_name) else {
+            return Ok(None);
+        };
 
         // Get model config if it exists
         let models = provider_config.models();
         let model_config = models.get(model_name);
+        let modality = model_config.map(|m| m.modality()).unwrap_or_default();
 
         // Check rate limit if token rate limiter is configured
         let Some(ref token_rate_limiter) = self.shared.token_rate_limiter else {
@@ -125,7 +242,7 @@ impl LlmServer {
                 "Token rate limiter not initialized - no providers have token rate limits configured. \
                 Allowing request without token rate limiting."
             );
-            return None;
+            return Ok(None);
         };
 
         // Gather provider and model rate limit configurations
@@ -134,9 +251,6 @@ impl LlmServer {
             model_config.and_then(|m| m.rate_limits()),
         );
 
-        // Count request tokens (input only, no output buffering)
-        let input_tokens = crate::token_counter::count_input_tokens(request);
-
         log::debug!("Token accounting: input={input_tokens} (output tokens not counted for rate limiting)",);
 
         // Create token rate limit request
@@ -145,6 +259,7 @@ impl LlmServer {
             group: context.group.clone(),
             provider: provider_name.to_string(),
             model: Some(model_name.to_string()),
+            modality,
             input_tokens,
         };
 
@@ -152,10 +267,10 @@ impl LlmServer {
             .check_request(&token_request, provider_limits, model_limits)
             .await
         {
-            Ok(duration) => duration,
+            Ok(duration) => Ok(duration),
             Err(e) => {
                 log::error!("Error checking token rate limit: {e}");
-                None
+                Err(LlmError::InternalError(None))
             }
         }
     }
@@ -168,9 +283,6 @@ impl LlmServer {
     ) -> crate::Result<ChatCompletionResponse> {
         // Note: Streaming is handled by completions_stream(), this method is for non-streaming only
 
-        // Check token rate limits first
-        self.check_and_enforce_rate_limit(&request, context).await?;
-
         // Extract provider name from the model string (format: "provider/model")
         let Some((provider_name, model_name)) = request.model.split_once('/') else {
             return Err(LlmError::InvalidModelFormat(request.model.clone()));
@@ -185,6 +297,19 @@ impl LlmServer {
             return Err(LlmError::ProviderNotFound(provider_name.to_string()));
         };
 
+        let input_tokens = crate::token_counter::count_input_tokens(&request);
+
+        self.check_and_enforce_rate_limit(provider_name, model_name, input_tokens, context)
+            .await?;
+        self.check_country_access(provider_name, model_name, context)?;
+        self.check_group_access(provider_name, model_name, context)?;
+        self.check_model_limits(
+            provider_name,
+            model_name,
+            request.max_tokens.unwrap_or(0) as usize,
+            input_tokens,
+        )?;
+
         // Store the original model name before stripping the prefix
         let original_model = request.model.clone();
         request.model = model_name.to_string();
@@ -207,9 +332,6 @@ impl LlmServer {
         mut request: ChatCompletionRequest,
         context: &RequestContext,
     ) -> crate::Result<ChatCompletionStream> {
-        // Check token rate limits first
-        self.check_and_enforce_rate_limit(&request, context).await?;
-
         // Extract provider name from the model string (format: "provider/model")
         let Some((provider_name, model_name)) = request.model.split_once('/') else {
             return Err(LlmError::InvalidModelFormat(request.model.clone()));
@@ -230,6 +352,19 @@ impl LlmServer {
             return Err(LlmError::StreamingNotSupported);
         }
 
+        let input_tokens = crate::token_counter::count_input_tokens(&request);
+
+        self.check_and_enforce_rate_limit(provider_name, model_name, input_tokens, context)
+            .await?;
+        self.check_country_access(provider_name, model_name, context)?;
+        self.check_group_access(provider_name, model_name, context)?;
+        self.check_model_limits(
+            provider_name,
+            model_name,
+            request.max_tokens.unwrap_or(0) as usize,
+            input_tokens,
+        )?;
+
         // Store the original model name for later
         let original_model = request.model.clone();
 
@@ -250,6 +385,45 @@ impl LlmServer {
 
         Ok(Box::pin(transformed_stream))
     }
+
+    /// Process a fill-in-the-middle completion request.
+    ///
+    /// Only models explicitly configured with `fim = true` accept this request; all
+    /// other models return `LlmError::FimNotSupported`.
+    pub async fn fim_completion(
+        &self,
+        mut request: FimCompletionRequest,
+        context: &RequestContext,
+    ) -> crate::Result<ChatCompletionResponse> {
+        let Some((provider_name, model_name)) = request.model.split_once('/') else {
+            return Err(LlmError::InvalidModelFormat(request.model.clone()));
+        };
+
+        let Some(provider) = self.get_provider(provider_name) else {
+            return Err(LlmError::ProviderNotFound(provider_name.to_string()));
+        };
+
+        let input_tokens = crate::token_counter::count_fim_input_tokens(&request);
+
+        self.check_and_enforce_rate_limit(provider_name, model_name, input_tokens, context)
+            .await?;
+        self.check_country_access(provider_name, model_name, context)?;
+        self.check_group_access(provider_name, model_name, context)?;
+        self.check_model_limits(
+            provider_name,
+            model_name,
+            request.max_tokens.unwrap_or(0) as usize,
+            input_tokens,
+        )?;
+
+        let original_model = request.model.clone();
+        request.model = model_name.to_string();
+
+        let mut response = provider.fim_completion(request, context).await?;
+        response.model = original_model;
+
+        Ok(response)
+    }
 }
 
 impl LlmService for LlmServer {
@@ -257,6 +431,10 @@ impl LlmService for LlmServer {
         self.models()
     }
 
+    fn country_header(&self) -> &str {
+        self.country_header()
+    }
+
     async fn completions(
         &self,
         request: ChatCompletionRequest,
@@ -272,4 +450,12 @@ impl LlmService for LlmServer {
     ) -> crate::Result<ChatCompletionStream> {
         self.completions_stream(request, context).await
     }
+
+    async fn fim_completion(
+        &self,
+        request: FimCompletionRequest,
+        context: &RequestContext,
+    ) -> crate::Result<ChatCompletionResponse> {
+        self.fim_completion(request, context).await
+    }
 }
@@ -8,8 +8,8 @@ use rate_limit::TokenRateLimitManager;
 use crate::{
     error::LlmError,
     provider::{
-        Provider, anthropic::AnthropicProvider, bedrock::BedrockProvider, google::GoogleProvider,
-        openai::OpenAIProvider,
+        Provider, anthropic::AnthropicProvider, azure::AzureProvider, bedrock::BedrockProvider,
+        google::GoogleProvider, openai::OpenAIProvider,
     },
     server::{LlmHandler, LlmServer, LlmServerInner, metrics::LlmServerWithMetrics},
 };
@@ -47,6 +47,23 @@ impl<'a> LlmServerBuilder<'a> {
                 config::LlmProviderConfig::Bedrock(bedrock_config) => {
                     Box::new(BedrockProvider::new(name.clone(), bedrock_config).await?)
                 }
+                // Mistral speaks the same chat-completions wire format as OpenAI.
+                config::LlmProviderConfig::Mistral(api_config) => {
+                    Box::new(OpenAIProvider::new(name.clone(), api_config)?)
+                }
+                // Ollama exposes an OpenAI-compatible `/v1` endpoint, so it reuses the same client.
+                config::LlmProviderConfig::Ollama(ollama_config) => {
+                    Box::new(OpenAIProvider::new(name.clone(), ollama_config.into())?)
+                }
+                config::LlmProviderConfig::Azure(azure_config) => {
+                    Box::new(AzureProvider::new(name.clone(), azure_config)?)
+                }
+                // Generic self-hosted/third-party backends speak the same wire format, so they
+                // reuse the same client; the configured `models` map acts as an allow-list via
+                // `ModelManager`.
+                config::LlmProviderConfig::OpenaiCompatible(compat_config) => {
+                    Box::new(OpenAIProvider::new(name.clone(), compat_config.into())?)
+                }
             };
 
             providers.push(provider);
@@ -71,12 +88,15 @@ impl<'a> LlmServerBuilder<'a> {
 
         let token_rate_limiter = if has_token_rate_limits {
             Some(
-                TokenRateLimitManager::new(&self.config.server.rate_limits.storage)
-                    .await
-                    .map_err(|e| {
-                        log::error!("Failed to initialize token rate limiter: {e}");
-                        LlmError::InternalError(None)
-                    })?,
+                TokenRateLimitManager::new(
+                    &self.config.server.rate_limits.storage,
+                    self.config.server.rate_limits.on_storage_error,
+                )
+                .await
+                .map_err(|e| {
+                    log::error!("Failed to initialize token rate limiter: {e}");
+                    LlmError::InternalError(None)
+                })?,
             )
         } else {
             None
@@ -1,7 +1,7 @@
 //! LLM handler that conditionally applies metrics
 
 use crate::{
-    messages::{ChatCompletionRequest, ChatCompletionResponse, ModelsResponse},
+    messages::{ChatCompletionRequest, ChatCompletionResponse, FimCompletionRequest, ModelsResponse},
     provider::ChatCompletionStream,
     request::RequestContext,
     server::{LlmServer, LlmService, metrics::LlmServerWithMetrics},
@@ -25,6 +25,14 @@ impl LlmHandler {
         }
     }
 
+    /// HTTP header consulted for the caller's country when evaluating geographic access control.
+    pub fn country_header(&self) -> &str {
+        match self {
+            LlmHandler::WithMetrics(server) => server.country_header(),
+            LlmHandler::WithoutMetrics(server) => server.country_header(),
+        }
+    }
+
     /// Process a chat completion request.
     pub async fn completions(
         &self,
@@ -48,4 +56,16 @@ impl LlmHandler {
             LlmHandler::WithoutMetrics(server) => server.completions_stream(request, context).await,
         }
     }
+
+    /// Process a fill-in-the-middle completion request.
+    pub async fn fim_completion(
+        &self,
+        request: FimCompletionRequest,
+        context: &RequestContext,
+    ) -> crate::Result<ChatCompletionResponse> {
+        match self {
+            LlmHandler::WithMetrics(server) => server.fim_completion(request, context).await,
+            LlmHandler::WithoutMetrics(server) => server.fim_completion(request, context).await,
+        }
+    }
 }
@@ -4,7 +4,7 @@ mod stream;
 
 use crate::{
     error::LlmError,
-    messages::{ChatCompletionRequest, ChatCompletionResponse, ModelsResponse},
+    messages::{ChatCompletionRequest, ChatCompletionResponse, FimCompletionRequest, ModelsResponse},
     provider::ChatCompletionStream,
     request::RequestContext,
     server::LlmService,
@@ -49,13 +49,17 @@ where
         self.inner.models()
     }
 
+    fn country_header(&self) -> &str {
+        self.inner.country_header()
+    }
+
     /// Process a chat completion request with metrics.
     async fn completions(
         &self,
         request: ChatCompletionRequest,
         context: &RequestContext,
     ) -> crate::Result<ChatCompletionResponse> {
-        let mut recorder = create_recorder(GEN_AI_CLIENT_OPERATION_DURATION, &request.model, context);
+        let mut recorder = create_recorder(GEN_AI_CLIENT_OPERATION_DURATION, "chat.completions", &request.model, context);
 
         let result = self.inner.completions(request.clone(), context).await;
 
@@ -100,8 +104,8 @@ where
         request: ChatCompletionRequest,
         context: &RequestContext,
     ) -> crate::Result<ChatCompletionStream> {
-        let operation_recorder = create_recorder(GEN_AI_CLIENT_OPERATION_DURATION, &request.model, context);
-        let ttft_recorder = create_recorder(GEN_AI_CLIENT_TIME_TO_FIRST_TOKEN, &request.model, context);
+        let operation_recorder = create_recorder(GEN_AI_CLIENT_OPERATION_DURATION, "chat.completions", &request.model, context);
+        let ttft_recorder = create_recorder(GEN_AI_CLIENT_TIME_TO_FIRST_TOKEN, "chat.completions", &request.model, context);
 
         let stream = self.inner.completions_stream(request.clone(), context).await?;
 
@@ -118,14 +122,53 @@ where
 
         Ok(Box::pin(metrics_stream))
     }
+
+    /// Process a fill-in-the-middle completion request with metrics.
+    async fn fim_completion(
+        &self,
+        request: FimCompletionRequest,
+        context: &RequestContext,
+    ) -> crate::Result<ChatCompletionResponse> {
+        let mut recorder = create_recorder(GEN_AI_CLIENT_OPERATION_DURATION, "fim.completions", &request.model, context);
+
+        let result = self.inner.fim_completion(request.clone(), context).await;
+
+        if let Err(ref e) = result {
+            let error_type_str = error_type(e);
+            recorder.push_attribute("error.type", error_type_str);
+        }
+
+        if let Ok(ref response) = result
+            && let Some(choice) = response.choices.first()
+        {
+            recorder.push_attribute("gen_ai.response.finish_reason", choice.finish_reason.to_string());
+        }
+
+        recorder.record();
+
+        // Record token metrics if the request was successful using actual counts from the LLM
+        if let Ok(ref response) = result {
+            let attributes = create_base_attributes(&request.model, context);
+
+            let input_tokens = response.usage.prompt_tokens as u64;
+            let output_tokens = response.usage.completion_tokens as u64;
+            let total_tokens = input_tokens + output_tokens;
+
+            self.input_token_counter.add(input_tokens, &attributes);
+            self.output_token_counter.add(output_tokens, &attributes);
+            self.total_token_counter.add(total_tokens, &attributes);
+        }
+
+        result
+    }
 }
 
 /// Create a recorder with common LLM attributes
-fn create_recorder(metric_name: &'static str, model: &str, context: &RequestContext) -> Recorder {
+fn create_recorder(metric_name: &'static str, operation_name: &str, model: &str, context: &RequestContext) -> Recorder {
     let mut recorder = Recorder::new(metric_name);
 
     recorder.push_attribute("gen_ai.system", "nexus.llm");
-    recorder.push_attribute("gen_ai.operation.name", "chat.completions");
+    recorder.push_attribute("gen_ai.operation.name", operation_name.to_string());
     recorder.push_attribute("gen_ai.request.model", model.to_string());
 
     // Add client identity if available
@@ -166,9 +209,11 @@ fn error_type(error: &LlmError) -> &'static str {
         LlmError::InvalidRequest(_) => "invalid_request",
         LlmError::AuthenticationFailed(_) => "authentication_failed",
         LlmError::InsufficientQuota(_) => "insufficient_quota",
+        LlmError::AccessDenied(_) => "access_denied",
         LlmError::ModelNotFound(_) => "model_not_found",
         LlmError::RateLimitExceeded { .. } => "rate_limit_exceeded",
         LlmError::StreamingNotSupported => "streaming_not_supported",
+        LlmError::FimNotSupported => "fim_not_supported",
         LlmError::InvalidModelFormat(_) => "invalid_model_format",
         LlmError::ProviderNotFound(_) => "provider_not_found",
         LlmError::InternalError(_) => "internal_error",
@@ -1,7 +1,7 @@
 //! LLM service trait for middleware composition
 
 use crate::{
-    messages::{ChatCompletionRequest, ChatCompletionResponse, ModelsResponse},
+    messages::{ChatCompletionRequest, ChatCompletionResponse, FimCompletionRequest, ModelsResponse},
     provider::ChatCompletionStream,
     request::RequestContext,
 };
@@ -11,6 +11,9 @@ pub(crate) trait LlmService: Send + Sync {
     /// List all available models from all providers.
     fn models(&self) -> ModelsResponse;
 
+    /// HTTP header consulted for the caller's country when evaluating geographic access control.
+    fn country_header(&self) -> &str;
+
     /// Process a chat completion request.
     fn completions(
         &self,
@@ -24,4 +27,11 @@ pub(crate) trait LlmService: Send + Sync {
         request: ChatCompletionRequest,
         context: &RequestContext,
     ) -> impl std::future::Future<Output = crate::Result<ChatCompletionStream>> + Send;
+
+    /// Process a fill-in-the-middle completion request.
+    fn fim_completion(
+        &self,
+        request: FimCompletionRequest,
+        context: &RequestContext,
+    ) -> impl std::future::Future<Output = crate::Result<ChatCompletionResponse>> + Send;
 }
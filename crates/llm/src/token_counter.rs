@@ -4,7 +4,7 @@ use std::sync::OnceLock;
 
 use tiktoken_rs::{CoreBPE, cl100k_base};
 
-use crate::messages::{ChatCompletionRequest, ChatMessage};
+use crate::messages::{ChatCompletionRequest, ChatMessage, FimCompletionRequest};
 
 /// Global tokenizer instance using cl100k_base encoding.
 static TOKENIZER: OnceLock<CoreBPE> = OnceLock::new();
@@ -55,6 +55,21 @@ pub(crate) fn count_input_tokens(request: &ChatCompletionRequest) -> usize {
     total
 }
 
+/// Count tokens in a fill-in-the-middle request.
+///
+/// FIM requests have no chat-style message framing, so this simply tokenizes the
+/// `prompt` and, if present, the `suffix` - no per-message formatting overhead applies.
+pub(crate) fn count_fim_input_tokens(request: &FimCompletionRequest) -> usize {
+    let tokenizer = get_tokenizer();
+    let mut total = tokenizer.encode_ordinary(&request.prompt).len();
+
+    if let Some(suffix) = &request.suffix {
+        total += tokenizer.encode_ordinary(suffix).len();
+    }
+
+    total
+}
+
 /// Count tokens in a single message.
 fn count_message_tokens(tokenizer: &CoreBPE, message: &ChatMessage) -> usize {
     let mut tokens = 0;
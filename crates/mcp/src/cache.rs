@@ -33,7 +33,15 @@ impl DynamicDownstreamCache {
         }
     }
 
-    pub async fn get_or_create(&self, token: &SecretString) -> anyhow::Result<Arc<CachedDownstream>> {
+    /// `trace_headers` is only used when this call actually builds a new downstream connection
+    /// (a genuine cache miss) - it has no effect on a cache hit, since the connection it would
+    /// have been baked into already exists. See `Downstream::new` and `create_client` for the
+    /// consequences of that.
+    pub async fn get_or_create(
+        &self,
+        token: &SecretString,
+        trace_headers: &[(String, String)],
+    ) -> anyhow::Result<Arc<CachedDownstream>> {
         // Hash token for cache key, so we can be sure nobody ever accidentally exposes it
         let cache_key = hash_token(token.expose_secret());
 
@@ -49,7 +57,7 @@ impl DynamicDownstreamCache {
         };
 
         // Create downstream with token - this will use finalize() to inject auth
-        let downstream = Downstream::new(&self.config, Some(token)).await?;
+        let downstream = Downstream::new(&self.config, Some(token), trace_headers).await?;
 
         // Create search tool with all downstream tools
         let tools: Vec<_> = downstream.list_tools().cloned().collect();
@@ -0,0 +1,198 @@
+//! Per-downstream circuit breaker.
+//!
+//! Tracks consecutive failures for a single downstream MCP server and, once
+//! [`config::CircuitBreakerConfig`]'s `failure_threshold` is hit within its
+//! `window`, opens the circuit so subsequent calls short-circuit immediately
+//! instead of paying the full downstream latency. After `cooldown` elapses,
+//! a single half-open probe call is let through: success closes the circuit,
+//! failure re-opens it.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use config::CircuitBreakerConfig;
+
+/// Circuit breaker state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    /// Calls are allowed through normally.
+    Closed,
+    /// Calls are short-circuited until `cooldown` elapses.
+    Open,
+    /// A single probe call is allowed through to decide whether to close or re-open.
+    HalfOpen,
+}
+
+struct Inner {
+    state: State,
+    consecutive_failures: u32,
+    window_start: Instant,
+    opened_at: Instant,
+}
+
+/// A circuit breaker guarding calls to a single downstream MCP server.
+pub struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    inner: Mutex<Inner>,
+}
+
+impl CircuitBreaker {
+    /// Create a new circuit breaker, starting closed.
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        let now = Instant::now();
+
+        Self {
+            config,
+            inner: Mutex::new(Inner {
+                state: State::Closed,
+                consecutive_failures: 0,
+                window_start: now,
+                opened_at: now,
+            }),
+        }
+    }
+
+    /// Returns `true` if a call should be allowed through right now. When the
+    /// circuit is open but `cooldown` has elapsed, this transitions to
+    /// half-open and allows exactly one probe call through.
+    pub fn try_acquire(&self) -> bool {
+        let mut inner = self.inner.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        match inner.state {
+            State::Closed => true,
+            State::HalfOpen => false,
+            State::Open => {
+                if inner.opened_at.elapsed() >= self.config.cooldown {
+                    inner.state = State::HalfOpen;
+
+                    true
+                } else {
+                    false
+                }
+            }
+        }
+    }
+
+    /// Record the outcome of a call that [`try_acquire`](Self::try_acquire) allowed through.
+    pub fn record_result(&self, success: bool) {
+        let mut inner = self.inner.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        match inner.state {
+            State::HalfOpen => {
+                if success {
+                    inner.state = State::Closed;
+                    inner.consecutive_failures = 0;
+                    inner.window_start = Instant::now();
+                } else {
+                    inner.state = State::Open;
+                    inner.opened_at = Instant::now();
+                }
+            }
+            State::Closed => {
+                if success {
+                    inner.consecutive_failures = 0;
+                } else {
+                    self.record_failure(&mut inner);
+                }
+            }
+            State::Open => {
+                // A call may have been in flight when the circuit opened from
+                // elsewhere; its outcome no longer affects the open circuit.
+            }
+        }
+    }
+
+    fn record_failure(&self, inner: &mut Inner) {
+        let now = Instant::now();
+
+        if now.duration_since(inner.window_start) > self.config.window {
+            inner.window_start = now;
+            inner.consecutive_failures = 0;
+        }
+
+        inner.consecutive_failures += 1;
+
+        if inner.consecutive_failures >= self.config.failure_threshold {
+            inner.state = State::Open;
+            inner.opened_at = now;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(failure_threshold: u32, window: Duration, cooldown: Duration) -> CircuitBreakerConfig {
+        CircuitBreakerConfig {
+            failure_threshold,
+            window,
+            cooldown,
+        }
+    }
+
+    #[test]
+    fn opens_after_consecutive_failures() {
+        let breaker = CircuitBreaker::new(config(3, Duration::from_secs(60), Duration::from_secs(30)));
+
+        for _ in 0..2 {
+            assert!(breaker.try_acquire());
+            breaker.record_result(false);
+        }
+
+        assert!(breaker.try_acquire());
+        breaker.record_result(false);
+
+        assert!(!breaker.try_acquire());
+    }
+
+    #[test]
+    fn success_resets_the_failure_count() {
+        let breaker = CircuitBreaker::new(config(3, Duration::from_secs(60), Duration::from_secs(30)));
+
+        breaker.record_result(false);
+        breaker.record_result(false);
+        breaker.record_result(true);
+        breaker.record_result(false);
+        breaker.record_result(false);
+
+        assert!(breaker.try_acquire());
+    }
+
+    #[test]
+    fn half_open_probe_closes_circuit_on_success() {
+        let cooldown = Duration::from_millis(20);
+        let breaker = CircuitBreaker::new(config(1, Duration::from_secs(60), cooldown));
+
+        // Open the circuit.
+        assert!(breaker.try_acquire());
+        breaker.record_result(false);
+        assert!(!breaker.try_acquire());
+
+        std::thread::sleep(cooldown * 2);
+
+        // Cooldown elapsed, so this acquire is the half-open probe.
+        assert!(breaker.try_acquire());
+        breaker.record_result(true);
+
+        // Circuit is closed again - repeated calls succeed without being gated.
+        assert!(breaker.try_acquire());
+        assert!(breaker.try_acquire());
+    }
+
+    #[test]
+    fn half_open_probe_reopens_circuit_on_failure() {
+        let cooldown = Duration::from_millis(20);
+        let breaker = CircuitBreaker::new(config(1, Duration::from_secs(60), cooldown));
+
+        assert!(breaker.try_acquire());
+        breaker.record_result(false);
+
+        std::thread::sleep(cooldown * 2);
+
+        assert!(breaker.try_acquire());
+        breaker.record_result(false);
+
+        assert!(!breaker.try_acquire());
+    }
+}
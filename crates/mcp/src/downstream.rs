@@ -47,7 +47,15 @@ impl Downstream {
     /// This method initializes all configured downstream servers and aggregates
     /// their tools, prefixing each tool name with the server name followed by "__".
     /// Server initialization and tool listing happens concurrently for better performance.
-    pub async fn new(config: &config::McpConfig, token: Option<&SecretString>) -> anyhow::Result<Self> {
+    ///
+    /// `trace_headers` carries the caller's distributed trace context (e.g. `traceparent`,
+    /// `baggage`) to re-inject into the connections opened to HTTP downstream servers. Pass an
+    /// empty slice when there's no ambient request context, such as at server startup.
+    pub async fn new(
+        config: &config::McpConfig,
+        token: Option<&SecretString>,
+        trace_headers: &[(String, String)],
+    ) -> anyhow::Result<Self> {
         struct DownstreamError(String, anyhow::Error);
 
         // Clone global headers to pass to each downstream client
@@ -59,6 +67,7 @@ impl Downstream {
         for (name, server_config) in &config.servers {
             let name = name.clone();
             let global_headers = global_headers.clone();
+            let trace_headers = trace_headers.to_vec();
 
             match server_config.finalize(token) {
                 McpServer::Stdio(stdio_config) if token.is_none() => {
@@ -91,9 +100,10 @@ impl Downstream {
                 McpServer::Http(http_config) if token.is_some() || !http_config.forwards_authentication() => {
                     server_futures.push(
                         async move {
-                            let server = DownstreamClient::new_http(&name, &http_config, global_headers.iter())
-                                .await
-                                .map_err(|err| DownstreamError(name.clone(), err))?;
+                            let server =
+                                DownstreamClient::new_http(&name, &http_config, global_headers.iter(), &trace_headers)
+                                    .await
+                                    .map_err(|err| DownstreamError(name.clone(), err))?;
 
                             let tools = server
                                 .list_tools()
@@ -250,7 +260,7 @@ impl Downstream {
     /// This method will parse the server name, find the appropriate server,
     /// and forward the call with the original tool name.
     #[fastrace::trace(name = "downstream:execute")]
-    pub async fn execute(&self, mut params: CallToolRequestParam) -> Result<CallToolResult, ErrorData> {
+    pub async fn execute(&self, params: CallToolRequestParam) -> Result<CallToolResult, ErrorData> {
         log::debug!("Executing downstream tool: '{}'", params.name);
 
         let error_fn = || ErrorData::method_not_found::<CallToolRequestMethod>();
@@ -264,16 +274,33 @@ impl Downstream {
             error_fn()
         })?;
 
-        let server = self.find_server(server_name).ok_or_else(|| {
-            log::debug!("Server '{server_name}' not found in downstream registry");
-            error_fn()
-        })?;
-
         if self.find_tool(&params.name).is_none() {
             log::error!("Tool '{}' not found in tool registry", params.name);
             return Err(error_fn());
         }
 
+        self.call_downstream_tool(server_name, tool_name, params).await
+    }
+
+    /// Calls a tool on an already-resolved downstream server, performing only the
+    /// round trip to the downstream server - no tool-name parsing or registry-lookup
+    /// checks.
+    ///
+    /// Callers that need to tell pre-flight errors (malformed or unknown tool name)
+    /// apart from genuine downstream failures - for example, to avoid tripping a
+    /// circuit breaker on a client's mistyped tool name - should resolve
+    /// `server_name`/`tool_name` themselves and call this directly instead of `execute`.
+    pub(crate) async fn call_downstream_tool(
+        &self,
+        server_name: &str,
+        tool_name: &str,
+        mut params: CallToolRequestParam,
+    ) -> Result<CallToolResult, ErrorData> {
+        let server = self.find_server(server_name).ok_or_else(|| {
+            log::debug!("Server '{server_name}' not found in downstream registry");
+            ErrorData::method_not_found::<CallToolRequestMethod>()
+        })?;
+
         params.name = Cow::Owned(tool_name.to_string());
 
         server.call_tool(params).await.map_err(|error| match error {
@@ -1,10 +1,7 @@
-use std::{fs, io::Read, sync::Arc};
+use std::{fs, sync::Arc};
 
 use config::{ClientAuthConfig, HttpConfig, StdioTarget, StdioTargetType, TlsClientConfig};
-use reqwest::{
-    Certificate, Identity,
-    header::{AUTHORIZATION, HeaderMap, HeaderValue},
-};
+use reqwest::header::{AUTHORIZATION, HeaderMap, HeaderName, HeaderValue};
 use rmcp::{
     RoleClient, ServiceError, ServiceExt,
     model::{
@@ -80,9 +77,10 @@ impl DownstreamClient {
         name: &str,
         config: &'a HttpConfig,
         global_headers: impl Iterator<Item = &'a config::McpHeaderRule> + Clone,
+        trace_headers: &[(String, String)],
     ) -> anyhow::Result<Self> {
         log::debug!("Creating HTTP downstream service for server '{name}'");
-        let service = http_service(config, global_headers).await?;
+        let service = http_service(config, global_headers, trace_headers).await?;
 
         Ok(Self {
             inner: Arc::new(Inner {
@@ -156,26 +154,27 @@ impl DownstreamClient {
 async fn http_service<'a>(
     config: &'a HttpConfig,
     global_headers: impl Iterator<Item = &'a config::McpHeaderRule> + Clone,
+    trace_headers: &[(String, String)],
 ) -> anyhow::Result<RunningService<RoleClient, ()>> {
     if config.uses_streamable_http() {
         log::debug!("Configuration explicitly requests streamable-http protocol");
-        return streamable_http_service(config, global_headers).await;
+        return streamable_http_service(config, global_headers, trace_headers).await;
     }
 
     if config.uses_sse() {
         log::debug!("Configuration explicitly requests SSE protocol");
-        return sse_service(config, global_headers).await;
+        return sse_service(config, global_headers, trace_headers).await;
     }
 
     log::debug!("Auto-detecting protocol: attempting streamable-http first");
-    match streamable_http_service(config, global_headers.clone()).await {
+    match streamable_http_service(config, global_headers.clone(), trace_headers).await {
         Ok(service) => Ok(service),
         Err(_) => {
             log::warn!(
                 "Streamable-http connection failed for URL '{}', falling back to SSE protocol",
                 config.url
             );
-            sse_service(config, global_headers).await
+            sse_service(config, global_headers, trace_headers).await
         }
     }
 }
@@ -184,6 +183,7 @@ async fn http_service<'a>(
 async fn streamable_http_service<'a>(
     config: &'a HttpConfig,
     global_headers: impl Iterator<Item = &'a config::McpHeaderRule> + Clone,
+    trace_headers: &[(String, String)],
 ) -> anyhow::Result<RunningService<RoleClient, ()>> {
     log::debug!("Initializing streamable-http downstream service");
 
@@ -191,6 +191,7 @@ async fn streamable_http_service<'a>(
         config.tls.as_ref(),
         config.auth.as_ref(),
         global_headers.chain(config.get_effective_header_rules()),
+        trace_headers,
     )?;
 
     let config = StreamableHttpClientTransportConfig::with_uri(config.url.to_string());
@@ -203,6 +204,7 @@ async fn streamable_http_service<'a>(
 async fn sse_service<'a>(
     config: &'a HttpConfig,
     global_headers: impl Iterator<Item = &'a config::McpHeaderRule> + Clone,
+    trace_headers: &[(String, String)],
 ) -> anyhow::Result<RunningService<RoleClient, ()>> {
     log::debug!("Initializing SSE (Server-Sent Events) downstream service");
 
@@ -222,6 +224,7 @@ async fn sse_service<'a>(
         config.tls.as_ref(),
         config.auth.as_ref(),
         global_headers.chain(config.get_effective_header_rules()),
+        trace_headers,
     )?;
 
     log::debug!("Successfully created HTTP client for SSE transport");
@@ -236,50 +239,23 @@ async fn sse_service<'a>(
 }
 
 /// Creates a configured reqwest HTTP client with optional TLS settings.
+///
+/// `trace_headers` (typically a `traceparent`/`baggage` pair built from the request that
+/// triggered this connection) are baked in as default headers alongside auth and the static
+/// header rules. Because the resulting client is wrapped in a long-lived, cached/reused
+/// `RunningService` (see `DynamicDownstreamCache`), these reflect the trace in effect when the
+/// connection was *established*, not necessarily the trace of every later call reusing it -
+/// reqwest's `Client` has no API to mutate default headers after `build()`.
 fn create_client<'a>(
     tls: Option<&TlsClientConfig>,
     auth: Option<&ClientAuthConfig>,
     header_rules: impl Iterator<Item = &'a config::McpHeaderRule>,
+    trace_headers: &[(String, String)],
 ) -> anyhow::Result<reqwest::Client> {
     let mut builder = reqwest::Client::builder();
 
     if let Some(tls) = tls {
-        builder = builder
-            .danger_accept_invalid_certs(!tls.verify_certs)
-            .danger_accept_invalid_hostnames(tls.accept_invalid_hostnames);
-
-        if let Some(ref path) = tls.root_ca_cert_path {
-            let mut pem = Vec::new();
-
-            let mut file = fs::File::open(path)?;
-            file.read_to_end(&mut pem)?;
-
-            let cert = Certificate::from_pem(&pem)?;
-            builder = builder.add_root_certificate(cert);
-        }
-
-        let identity = tls.client_cert_path.as_ref().zip(tls.client_key_path.as_ref());
-
-        if let Some((cert_path, key_path)) = identity {
-            let mut cert_pem = Vec::new();
-            let mut cert_file = fs::File::open(cert_path)?;
-            cert_file.read_to_end(&mut cert_pem)?;
-
-            // Read client private key
-            let mut key_pem = Vec::new();
-            let mut key_file = fs::File::open(key_path)?;
-            key_file.read_to_end(&mut key_pem)?;
-
-            // Combine certificate and key into a single PEM bundle
-            let mut combined_pem = Vec::new();
-            combined_pem.extend_from_slice(&cert_pem);
-            combined_pem.extend_from_slice(b"\n");
-            combined_pem.extend_from_slice(&key_pem);
-
-            // Create identity from the combined PEM
-            let identity = Identity::from_pem(&combined_pem)?;
-            builder = builder.identity(identity);
-        }
+        builder = config::apply_to_reqwest_builder(builder, tls)?;
     }
 
     // Apply default headers based on auth and header rules
@@ -299,6 +275,14 @@ fn create_client<'a>(
         }
     }
 
+    // Re-inject the caller's distributed trace context, so it rides along on requests to the
+    // downstream MCP server instead of stopping at the gateway.
+    for (name, value) in trace_headers {
+        let name = HeaderName::from_bytes(name.as_bytes())?;
+        let value = HeaderValue::from_str(value)?;
+        headers.insert(name, value);
+    }
+
     if !headers.is_empty() {
         builder = builder.default_headers(headers);
     }
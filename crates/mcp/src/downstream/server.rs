@@ -1,6 +1,6 @@
 use std::{io::Read, sync::Arc};
 
-use config::{McpServer, SseConfig, StreamableHttpConfig, TlsClientConfig};
+use config::{McpServer, RootCertificateSource, SseConfig, StreamableHttpConfig, TlsClientConfig};
 use reqwest::{Certificate, Identity};
 use rmcp::{
     RoleClient, ServiceError, ServiceExt,
@@ -108,6 +108,16 @@ fn create_client(tls: Option<&TlsClientConfig>) -> anyhow::Result<reqwest::Clien
             .danger_accept_invalid_certs(!tls.verify_certs)
             .danger_accept_invalid_hostnames(tls.accept_invalid_hostnames);
 
+        builder = match tls.root_certificates {
+            RootCertificateSource::System => builder.tls_built_in_native_certs(true).tls_built_in_webpki_certs(false),
+            RootCertificateSource::Webpki => builder.tls_built_in_native_certs(false).tls_built_in_webpki_certs(true),
+            RootCertificateSource::Custom => builder.tls_built_in_native_certs(false).tls_built_in_webpki_certs(false),
+        };
+
+        if tls.root_certificates == RootCertificateSource::Custom && tls.root_ca_cert_path.is_none() {
+            anyhow::bail!("TLS root_certificates is set to 'custom' but no root_ca_cert_path was provided");
+        }
+
         if let Some(ref path) = tls.root_ca_cert_path {
             let mut pem = Vec::new();
 
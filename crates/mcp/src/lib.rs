@@ -3,6 +3,7 @@
 #![deny(missing_docs)]
 
 mod cache;
+mod circuit_breaker;
 mod config;
 mod downstream;
 mod index;
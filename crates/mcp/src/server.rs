@@ -8,6 +8,7 @@ use self::builder::McpServerBuilder;
 use crate::cache::DynamicDownstreamCache;
 use config::{Config, McpConfig};
 use execute::ExecuteParameters;
+use fastrace::collector::SpanContext;
 use http::request::Parts;
 use indoc::indoc;
 use itertools::Itertools;
@@ -23,11 +24,16 @@ use rmcp::{
 };
 use search::{SearchParameters, SearchTool};
 use secrecy::SecretString;
-use std::collections::{BTreeMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::{ops::Deref, sync::Arc};
+use telemetry::propagation::{Baggage, format_w3c_baggage, format_w3c_traceparent};
 
+use crate::circuit_breaker::CircuitBreaker;
 use crate::downstream::Downstream;
 
+/// JSON-RPC server-defined error code for a downstream call rejected by an open circuit breaker.
+const CIRCUIT_OPEN_ERROR_CODE: ErrorCode = ErrorCode(-32010);
+
 #[derive(Clone)]
 pub(crate) struct McpServer {
     shared: Arc<McpServerInner>,
@@ -45,6 +51,8 @@ pub(crate) struct McpServerInner {
     cache: Arc<DynamicDownstreamCache>,
     // Rate limit manager for server/tool limits
     rate_limit_manager: Option<Arc<rate_limit::RateLimitManager>>,
+    // Circuit breakers for servers that have one configured, keyed by server name
+    circuit_breakers: HashMap<String, Arc<CircuitBreaker>>,
     // Configuration for structured content responses
     enable_structured_content: bool,
     // List of tools
@@ -92,7 +100,8 @@ impl McpServer {
                 static_config.servers.len()
             );
 
-            let downstream = Downstream::new(&static_config, None).await?;
+            // No request context exists yet at server startup, so there's no trace to propagate.
+            let downstream = Downstream::new(&static_config, None, &[]).await?;
             let tools = downstream.list_tools().cloned().collect();
             let static_search_tool = SearchTool::new(tools)?;
 
@@ -104,6 +113,18 @@ impl McpServer {
         // Create cache for dynamic instances
         let cache = Arc::new(DynamicDownstreamCache::new(config.mcp.clone()));
 
+        // Build a circuit breaker for every server that has one configured
+        let circuit_breakers = config
+            .mcp
+            .servers
+            .iter()
+            .filter_map(|(name, server)| {
+                server
+                    .circuit_breaker()
+                    .map(|cb_config| (name.clone(), Arc::new(CircuitBreaker::new(cb_config.clone()))))
+            })
+            .collect();
+
         let server_info = Implementation {
             name: generate_server_name(&config.mcp),
             version: env!("CARGO_PKG_VERSION").to_string(),
@@ -125,6 +146,7 @@ impl McpServer {
             dynamic_server_names,
             cache,
             rate_limit_manager,
+            circuit_breakers,
             enable_structured_content: config.mcp.enable_structured_content,
             tools: vec![search::rmcp_tool(), execute::rmcp_tool()],
         };
@@ -135,7 +157,11 @@ impl McpServer {
     }
 
     /// Get or create cached search tool for the given authentication context
-    async fn get_search_tool(&self, token: Option<&SecretString>) -> Result<Arc<SearchTool>, ErrorData> {
+    async fn get_search_tool(
+        &self,
+        token: Option<&SecretString>,
+        trace_headers: &[(String, String)],
+    ) -> Result<Arc<SearchTool>, ErrorData> {
         match token {
             Some(token) if !self.dynamic_server_names.is_empty() => {
                 log::debug!("Retrieving combined search tool (static + dynamic servers)");
@@ -143,7 +169,7 @@ impl McpServer {
                 // Dynamic case - get from cache
                 let cached = self
                     .cache
-                    .get_or_create(token)
+                    .get_or_create(token, trace_headers)
                     .await
                     .map_err(|e| ErrorData::internal_error(format!("Failed to load dynamic tools: {e}"), None))?;
 
@@ -173,9 +199,10 @@ impl McpServer {
         // Extract token from request
         let parts = ctx.extensions.get::<Parts>();
         let token = parts.and_then(|p| p.extensions.get::<SecretString>()).cloned();
+        let trace_headers = outbound_trace_headers(parts);
 
         // Get the search tool to access all tools
-        let search_tool = self.get_search_tool(token.as_ref()).await?;
+        let search_tool = self.get_search_tool(token.as_ref(), &trace_headers).await?;
 
         // Check if tool exists in our registry
         if search_tool.find_exact(&params.name).is_none() {
@@ -203,8 +230,26 @@ impl McpServer {
 
             if let Err(e) = manager.check_request(&rate_limit_request).await {
                 log::debug!("Rate limit exceeded for tool '{}': {e:?}", params.name);
-                // Use -32000 for rate limit errors (server-defined error in JSON-RPC 2.0 spec)
-                return Err(ErrorData::new(ErrorCode(-32000), "Rate limit exceeded", None));
+
+                // This check happens past the point where the HTTP rate limit layer still has a
+                // response to attach headers to, so hand the decision back via the slot it left
+                // in the request extensions, if `response_headers` is enabled.
+                let info = rate_limit::RateLimitHeaderInfo::from_error(&e);
+
+                if let Some(info) = info
+                    && let Some(slot) = parts.and_then(|p| p.extensions.get::<rate_limit::RateLimitDecisionSlot>())
+                {
+                    slot.set(info);
+                }
+
+                // Use -32000 for rate limit errors (server-defined error in JSON-RPC 2.0 spec).
+                // Stash the same info in `data` so a Nexus instance proxying us as a downstream
+                // can recover it and apply it to its own response headers.
+                return Err(ErrorData::new(
+                    ErrorCode(-32000),
+                    "Rate limit exceeded",
+                    info.map(rate_limit::RateLimitHeaderInfo::to_error_data),
+                ));
             }
 
             log::debug!("Rate limit check passed for tool '{}'", params.name);
@@ -215,8 +260,23 @@ impl McpServer {
         // MCP header rules are applied at client initialization time, not per-request
         // No dynamic header transformation needed here
 
+        // Check the circuit breaker for this server, if one is configured
+        let circuit_breaker = self.circuit_breakers.get(server_name);
+
+        if let Some(breaker) = circuit_breaker
+            && !breaker.try_acquire()
+        {
+            log::debug!("Circuit open for server '{server_name}' - short-circuiting call");
+
+            return Err(ErrorData::new(
+                CIRCUIT_OPEN_ERROR_CODE,
+                format!("Server '{server_name}' is temporarily unavailable (circuit open)"),
+                None,
+            ));
+        }
+
         // Route to appropriate downstream
-        if self.dynamic_server_names.contains(server_name) {
+        let result = if self.dynamic_server_names.contains(server_name) {
             // Dynamic server - need token
             let token_ref = token.as_ref().ok_or_else(|| {
                 ErrorData::new(
@@ -228,11 +288,11 @@ impl McpServer {
 
             let cached = self
                 .cache
-                .get_or_create(token_ref)
+                .get_or_create(token_ref, &trace_headers)
                 .await
                 .map_err(|e| ErrorData::internal_error(format!("Failed to initialize: {e}"), None))?;
 
-            cached.downstream.execute(params).await
+            cached.downstream.call_downstream_tool(server_name, tool_name, params).await
         } else {
             // Static server
             let downstream = self
@@ -240,21 +300,43 @@ impl McpServer {
                 .as_ref()
                 .ok_or_else(ErrorData::method_not_found::<CallToolRequestMethod>)?; // Tool not found
 
-            downstream.execute(params).await
+            downstream.call_downstream_tool(server_name, tool_name, params).await
+        };
+
+        // Only the round trip to the downstream server reflects its health; the tool
+        // name/existence checks above are client-side and shouldn't trip the breaker.
+        if let Some(breaker) = circuit_breaker {
+            breaker.record_result(result.is_ok());
+        }
+
+        // The downstream may itself be a Nexus instance that just rate-limited this call and
+        // stashed its quota/retry info in the error `data` (see above) - recover it and apply
+        // it to our own response headers rather than silently dropping it on the floor.
+        if let Err(ref err) = result
+            && err.code.0 == -32000
+            && let Some(info) = rate_limit::RateLimitHeaderInfo::from_downstream_error_data(err.data.as_ref())
+            && let Some(slot) = parts.and_then(|p| p.extensions.get::<rate_limit::RateLimitDecisionSlot>())
+        {
+            slot.set(info);
         }
+
+        result
     }
 
     /// Get the appropriate downstream instance for the given token
-    async fn get_downstream(&self, token: Option<&SecretString>) -> Result<Arc<Downstream>, ErrorData> {
+    async fn get_downstream(
+        &self,
+        token: Option<&SecretString>,
+        trace_headers: &[(String, String)],
+    ) -> Result<Arc<Downstream>, ErrorData> {
         match token {
             Some(token) if !self.dynamic_server_names.is_empty() => {
                 log::debug!("Retrieving combined downstream instance (static + dynamic)");
 
                 // Dynamic case - get from cache
-                let cached =
-                    self.cache.get_or_create(token).await.map_err(|e| {
-                        ErrorData::internal_error(format!("Failed to load dynamic downstream: {e}"), None)
-                    })?;
+                let cached = self.cache.get_or_create(token, trace_headers).await.map_err(|e| {
+                    ErrorData::internal_error(format!("Failed to load dynamic downstream: {e}"), None)
+                })?;
 
                 Ok(Arc::new(cached.downstream.clone()))
             }
@@ -295,13 +377,14 @@ impl ServerHandler for McpServer {
         // Extract token from request extensions
         let parts = ctx.extensions.get::<Parts>();
         let token = parts.and_then(|p| p.extensions.get::<SecretString>());
+        let trace_headers = outbound_trace_headers(parts);
 
         match params.name.as_ref() {
             "search" => {
                 log::debug!("Executing search tool to find available MCP tools");
 
                 // Get cached search tool
-                let search_tool = self.get_search_tool(token).await?;
+                let search_tool = self.get_search_tool(token, &trace_headers).await?;
 
                 let search_params: SearchParameters =
                     serde_json::from_value(serde_json::Value::Object(params.arguments.unwrap_or_default()))
@@ -374,12 +457,11 @@ impl ServerHandler for McpServer {
         log::debug!("Listing all available MCP prompts");
 
         // Extract token from request extensions
-        let token = ctx
-            .extensions
-            .get::<Parts>()
-            .and_then(|parts| parts.extensions.get::<SecretString>());
+        let parts = ctx.extensions.get::<Parts>();
+        let token = parts.and_then(|parts| parts.extensions.get::<SecretString>());
+        let trace_headers = outbound_trace_headers(parts);
 
-        let downstream = self.get_downstream(token).await?;
+        let downstream = self.get_downstream(token, &trace_headers).await?;
         let prompts = downstream.list_prompts().cloned().collect();
 
         Ok(ListPromptsResult {
@@ -396,12 +478,11 @@ impl ServerHandler for McpServer {
         log::debug!("Retrieving prompt details for '{}'", params.name);
 
         // Extract token from request extensions
-        let token = ctx
-            .extensions
-            .get::<Parts>()
-            .and_then(|parts| parts.extensions.get::<SecretString>());
+        let parts = ctx.extensions.get::<Parts>();
+        let token = parts.and_then(|parts| parts.extensions.get::<SecretString>());
+        let trace_headers = outbound_trace_headers(parts);
 
-        let downstream = self.get_downstream(token).await?;
+        let downstream = self.get_downstream(token, &trace_headers).await?;
         downstream.get_prompt(params).await
     }
 
@@ -413,12 +494,11 @@ impl ServerHandler for McpServer {
         log::debug!("Listing all available MCP resources");
 
         // Extract token from request extensions
-        let token = ctx
-            .extensions
-            .get::<Parts>()
-            .and_then(|parts| parts.extensions.get::<SecretString>());
+        let parts = ctx.extensions.get::<Parts>();
+        let token = parts.and_then(|parts| parts.extensions.get::<SecretString>());
+        let trace_headers = outbound_trace_headers(parts);
 
-        let downstream = self.get_downstream(token).await?;
+        let downstream = self.get_downstream(token, &trace_headers).await?;
         let resources = downstream.list_resources().cloned().collect();
 
         Ok(ListResourcesResult {
@@ -435,16 +515,39 @@ impl ServerHandler for McpServer {
         log::debug!("Reading resource content for URI: '{}'", params.uri);
 
         // Extract token from request extensions
-        let token = ctx
-            .extensions
-            .get::<Parts>()
-            .and_then(|parts| parts.extensions.get::<SecretString>());
+        let parts = ctx.extensions.get::<Parts>();
+        let token = parts.and_then(|parts| parts.extensions.get::<SecretString>());
+        let trace_headers = outbound_trace_headers(parts);
 
-        let downstream = self.get_downstream(token).await?;
+        let downstream = self.get_downstream(token, &trace_headers).await?;
         downstream.read_resource(params).await
     }
 }
 
+/// Builds the `traceparent`/`baggage` header pair to re-inject into outbound requests to
+/// downstream MCP servers, from the trace context the HTTP tracing layer attached to this
+/// request. Returns an empty `Vec` if there's no context to propagate (tracing disabled, no
+/// parent context, or no `Parts` available at all).
+fn outbound_trace_headers(parts: Option<&Parts>) -> Vec<(String, String)> {
+    let Some(parts) = parts else {
+        return Vec::new();
+    };
+
+    let mut headers = Vec::new();
+
+    if let Some(context) = parts.extensions.get::<SpanContext>() {
+        headers.push(("traceparent".to_string(), format_w3c_traceparent(context)));
+    }
+
+    if let Some(baggage) = parts.extensions.get::<Baggage>()
+        && let Some(value) = format_w3c_baggage(baggage)
+    {
+        headers.push(("baggage".to_string(), value));
+    }
+
+    headers
+}
+
 fn generate_server_name(config: &McpConfig) -> String {
     if config.servers.is_empty() {
         "Tool Aggregator".to_string()
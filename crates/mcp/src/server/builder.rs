@@ -42,6 +42,14 @@ impl McpServerBuilder {
             .is_some_and(|t| t.traces_otlp_config().is_some());
 
         let mcp_config = self.config.mcp.clone();
+        let error_type_overrides = Arc::new(mcp_config.error_type_overrides.clone());
+        let log_levels = Arc::new(
+            self.config
+                .telemetry
+                .as_ref()
+                .map(|t| t.log_levels().clone())
+                .unwrap_or_default(),
+        );
         let mcp_server = McpServer::new(self).await?;
 
         // Build the middleware pipeline using the enum
@@ -54,7 +62,10 @@ impl McpServerBuilder {
             (true, true) => {
                 // Both tracing and metrics: tracing -> metrics -> server
                 log::debug!("Creating MCP handler with full telemetry (tracing + metrics)");
-                McpHandler::WithFullTelemetry(TracingMiddleware::new(MetricsMiddleware::new(mcp_server), mcp_config))
+                McpHandler::WithFullTelemetry(TracingMiddleware::new(
+                    MetricsMiddleware::new(mcp_server, error_type_overrides, log_levels),
+                    mcp_config,
+                ))
             }
             (true, false) => {
                 // Only tracing
@@ -64,7 +75,7 @@ impl McpServerBuilder {
             (false, true) => {
                 // Only metrics
                 log::debug!("Creating MCP handler with metrics only");
-                McpHandler::WithMetricsOnly(MetricsMiddleware::new(mcp_server))
+                McpHandler::WithMetricsOnly(MetricsMiddleware::new(mcp_server, error_type_overrides, log_levels))
             }
             (false, false) => {
                 // No telemetry
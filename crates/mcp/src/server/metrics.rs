@@ -1,5 +1,9 @@
 //! Middleware for recording MCP tool call metrics
 
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use config::LogSeverity;
 use http::request::Parts;
 use rmcp::{
     RoleServer, ServerHandler,
@@ -18,12 +22,27 @@ use telemetry::metrics::{
 #[derive(Clone)]
 pub struct MetricsMiddleware<H> {
     inner: H,
+    // Custom downstream error code -> error_type label overrides, see
+    // `config::McpConfig::error_type_overrides`.
+    error_type_overrides: Arc<BTreeMap<i32, String>>,
+    // Per-error_type log level overrides, see `config::TelemetryConfig::log_levels`.
+    log_levels: Arc<BTreeMap<String, LogSeverity>>,
 }
 
 impl<H> MetricsMiddleware<H> {
-    /// Create a new metrics middleware wrapping the given handler
-    pub fn new(inner: H) -> Self {
-        Self { inner }
+    /// Create a new metrics middleware wrapping the given handler, with a
+    /// table of custom downstream error code overrides for the `error_type`
+    /// metric attribute and a table of log level overrides per `error_type`.
+    pub fn new(
+        inner: H,
+        error_type_overrides: Arc<BTreeMap<i32, String>>,
+        log_levels: Arc<BTreeMap<String, LogSeverity>>,
+    ) -> Self {
+        Self {
+            inner,
+            error_type_overrides,
+            log_levels,
+        }
     }
 }
 
@@ -55,7 +74,14 @@ where
         // Add result-specific attributes and record
         match &result {
             Ok(res) => add_success_attributes(&mut recorder, &tool_name, actual_tool.as_deref(), res),
-            Err(e) => add_error_attributes(&mut recorder, &tool_name, actual_tool.as_deref(), e),
+            Err(e) => add_error_attributes(
+                &mut recorder,
+                &tool_name,
+                actual_tool.as_deref(),
+                e,
+                &self.error_type_overrides,
+                &self.log_levels,
+            ),
         };
 
         recorder.record();
@@ -72,7 +98,7 @@ where
         let mut recorder = create_method_recorder("list_tools", &context);
         let result = self.inner.list_tools(params, context).await;
 
-        map_result_attributes(&mut recorder, &result);
+        map_result_attributes(&mut recorder, "list_tools", &result, &self.error_type_overrides, &self.log_levels);
         recorder.record();
 
         result
@@ -86,7 +112,7 @@ where
         let mut recorder = create_method_recorder("list_prompts", &context);
         let result = self.inner.list_prompts(params, context).await;
 
-        map_result_attributes(&mut recorder, &result);
+        map_result_attributes(&mut recorder, "list_prompts", &result, &self.error_type_overrides, &self.log_levels);
         recorder.record();
 
         result
@@ -100,7 +126,7 @@ where
         let mut recorder = create_method_recorder("get_prompt", &context);
         let result = self.inner.get_prompt(params, context).await;
 
-        map_result_attributes(&mut recorder, &result);
+        map_result_attributes(&mut recorder, "get_prompt", &result, &self.error_type_overrides, &self.log_levels);
         recorder.record();
 
         result
@@ -114,7 +140,7 @@ where
         let mut recorder = create_method_recorder("list_resources", &context);
         let result = self.inner.list_resources(params, context).await;
 
-        map_result_attributes(&mut recorder, &result);
+        map_result_attributes(&mut recorder, "list_resources", &result, &self.error_type_overrides, &self.log_levels);
         recorder.record();
 
         result
@@ -128,7 +154,7 @@ where
         let mut recorder = create_method_recorder("read_resource", &context);
         let result = self.inner.read_resource(params, context).await;
 
-        map_result_attributes(&mut recorder, &result);
+        map_result_attributes(&mut recorder, "read_resource", &result, &self.error_type_overrides, &self.log_levels);
         recorder.record();
 
         result
@@ -209,9 +235,18 @@ fn add_success_attributes(
 }
 
 /// Add error-specific attributes
-fn add_error_attributes(recorder: &mut Recorder, tool_name: &str, actual_tool: Option<&str>, error: &ErrorData) {
+fn add_error_attributes(
+    recorder: &mut Recorder,
+    tool_name: &str,
+    actual_tool: Option<&str>,
+    error: &ErrorData,
+    error_type_overrides: &BTreeMap<i32, String>,
+    log_levels: &BTreeMap<String, LogSeverity>,
+) {
     recorder.push_attribute("status", "error");
-    recorder.push_attribute("error.type", map_error_type(error.code));
+    let error_type = map_error_type(error.code, error_type_overrides);
+    log_tool_call_error(tool_name, &error_type, error, log_levels);
+    recorder.push_attribute("error.type", error_type);
 
     match tool_name {
         "search" => {
@@ -274,8 +309,18 @@ fn add_search_result_count(recorder: &mut Recorder, res: &CallToolResult) {
     }
 }
 
-/// Map error codes to readable error types
-fn map_error_type(code: ErrorCode) -> &'static str {
+/// Map error codes to readable error types.
+///
+/// Checks `error_type_overrides` first - operator-configured labels for
+/// custom server-defined codes (see [`config::McpConfig::error_type_overrides`]) -
+/// before falling back to the well-known JSON-RPC and Nexus-internal codes.
+/// Anything left over in the server-defined range (`-32001` to `-32099`)
+/// collapses into the generic `server_error` bucket.
+fn map_error_type(code: ErrorCode, error_type_overrides: &BTreeMap<i32, String>) -> String {
+    if let Some(label) = error_type_overrides.get(&code.0) {
+        return label.clone();
+    }
+
     match code {
         // JSON-RPC 2.0 standard error codes
         ErrorCode::PARSE_ERROR => "parse_error",         // -32700: Invalid JSON
@@ -287,11 +332,50 @@ fn map_error_type(code: ErrorCode) -> &'static str {
         // Server-defined errors (-32000 to -32099)
         // These might be used for application-specific errors like rate limiting
         _ if code.0 == -32000 => "rate_limit_exceeded",
+        _ if code.0 == -32010 => "circuit_open",
         _ if code.0 >= -32099 && code.0 <= -32001 => "server_error",
 
         // Any other error
         _ => "unknown",
     }
+    .to_string()
+}
+
+/// The log level an `error_type` is logged at absent an operator override.
+///
+/// Expected, client-caused errors log quietly so they don't spam operator
+/// logs with benign events; anything else is assumed to indicate a real
+/// problem downstream or in Nexus itself and logs as an error.
+fn default_log_severity(error_type: &str) -> log::Level {
+    match error_type {
+        "method_not_found" | "invalid_params" | "rate_limit_exceeded" => log::Level::Debug,
+        _ => log::Level::Error,
+    }
+}
+
+/// Log a tool call failure at the severity configured for its `error_type`
+/// (see `config::TelemetryConfig::log_levels`), falling back to
+/// [`default_log_severity`] when no override is configured.
+///
+/// This only affects the tracing log level of this event - the `error.type`
+/// metric attribute recorded alongside it is unaffected.
+fn log_tool_call_error(
+    tool_name: &str,
+    error_type: &str,
+    error: &ErrorData,
+    log_levels: &BTreeMap<String, LogSeverity>,
+) {
+    let level = log_levels
+        .get(error_type)
+        .map(|severity| severity.as_log_level())
+        .unwrap_or_else(|| default_log_severity(error_type));
+
+    log::log!(
+        level,
+        "Tool call '{tool_name}' failed: error_type={error_type}, code={}, message={}",
+        error.code.0,
+        error.message
+    );
 }
 
 /// Create a recorder for MCP method calls with the appropriate metric
@@ -311,14 +395,79 @@ fn create_method_recorder(method_name: &str, context: &RequestContext<RoleServer
 }
 
 /// Map result status to recorder attributes
-fn map_result_attributes<T>(recorder: &mut Recorder, result: &Result<T, ErrorData>) {
+fn map_result_attributes<T>(
+    recorder: &mut Recorder,
+    method_name: &str,
+    result: &Result<T, ErrorData>,
+    error_type_overrides: &BTreeMap<i32, String>,
+    log_levels: &BTreeMap<String, LogSeverity>,
+) {
     match result {
         Ok(_) => {
             recorder.push_attribute("status", "success");
         }
         Err(e) => {
             recorder.push_attribute("status", "error");
-            recorder.push_attribute("error.type", map_error_type(e.code));
+            let error_type = map_error_type(e.code, error_type_overrides);
+            log_tool_call_error(method_name, &error_type, e, log_levels);
+            recorder.push_attribute("error.type", error_type);
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn well_known_json_rpc_codes_map_without_overrides() {
+        let overrides = BTreeMap::new();
+
+        assert_eq!(map_error_type(ErrorCode::PARSE_ERROR, &overrides), "parse_error");
+        assert_eq!(map_error_type(ErrorCode::INVALID_REQUEST, &overrides), "invalid_request");
+        assert_eq!(map_error_type(ErrorCode::METHOD_NOT_FOUND, &overrides), "method_not_found");
+        assert_eq!(map_error_type(ErrorCode::INVALID_PARAMS, &overrides), "invalid_params");
+        assert_eq!(map_error_type(ErrorCode::INTERNAL_ERROR, &overrides), "internal_error");
+    }
+
+    #[test]
+    fn server_defined_codes_map_to_known_buckets_without_overrides() {
+        let overrides = BTreeMap::new();
+
+        assert_eq!(map_error_type(ErrorCode(-32000), &overrides), "rate_limit_exceeded");
+        assert_eq!(map_error_type(ErrorCode(-32010), &overrides), "circuit_open");
+        assert_eq!(map_error_type(ErrorCode(-32050), &overrides), "server_error");
+        assert_eq!(map_error_type(ErrorCode(-32099), &overrides), "server_error");
+    }
+
+    #[test]
+    fn unrecognized_codes_map_to_unknown() {
+        let overrides = BTreeMap::new();
+
+        assert_eq!(map_error_type(ErrorCode(-1), &overrides), "unknown");
+        assert_eq!(map_error_type(ErrorCode(-32100), &overrides), "unknown");
+    }
+
+    #[test]
+    fn configured_override_takes_precedence_over_built_in_classification() {
+        let overrides = BTreeMap::from([(-32001, "quota_exceeded".to_string())]);
+
+        // -32001 would otherwise fall into the generic `server_error` bucket.
+        assert_eq!(map_error_type(ErrorCode(-32001), &overrides), "quota_exceeded");
+    }
+
+    #[test]
+    fn override_can_relabel_a_well_known_code() {
+        let overrides = BTreeMap::from([(-32000, "throttled".to_string())]);
+
+        assert_eq!(map_error_type(ErrorCode(-32000), &overrides), "throttled");
+    }
+
+    #[test]
+    fn override_for_an_unrelated_code_does_not_affect_others() {
+        let overrides = BTreeMap::from([(-32005, "custom_error".to_string())]);
+
+        assert_eq!(map_error_type(ErrorCode(-32000), &overrides), "rate_limit_exceeded");
+        assert_eq!(map_error_type(ErrorCode(-32005), &overrides), "custom_error");
+    }
+}
@@ -11,6 +11,7 @@ use rmcp::{
     },
     service::RequestContext,
 };
+use telemetry::propagation::Baggage;
 
 /// Wrapper that adds distributed tracing to an MCP server
 #[derive(Clone)]
@@ -222,6 +223,20 @@ fn add_client_identity_to_span(span: &Span, parts: &Parts) {
     }
 }
 
+/// Add W3C Baggage entries to a span as attributes, so they ride along with
+/// the rest of the gateway's own distributed trace.
+fn add_baggage_to_span(span: &Span, parts: &Parts) {
+    let Some(baggage) = parts.extensions.get::<Baggage>() else {
+        return;
+    };
+
+    for (key, value) in &baggage.0 {
+        let key = format!("baggage.{key}");
+        let value = value.clone();
+        span.add_property(move || (key, value));
+    }
+}
+
 /// Categorize a tool and determine its transport type
 fn categorize_tool(tool_name: &str, config: &McpConfig) -> (&'static str, Option<&'static str>) {
     match tool_name {
@@ -256,9 +271,10 @@ fn create_span_with_context(context: &RequestContext<RoleServer>, name: &'static
         Span::enter_with_local_parent(name)
     };
 
-    // Add client identification if available
+    // Add client identification and baggage if available
     if let Some(parts) = context.extensions.get::<Parts>() {
         add_client_identity_to_span(&span, parts);
+        add_baggage_to_span(&span, parts);
     }
 
     // Add method name
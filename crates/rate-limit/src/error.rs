@@ -9,6 +9,8 @@ pub enum RateLimitError {
     /// Global rate limit exceeded.
     #[error("Global rate limit exceeded")]
     GlobalLimitExceeded {
+        /// The configured quota for the window that was exceeded.
+        limit: u32,
         /// Time to wait before retrying.
         retry_after: Duration,
     },
@@ -16,6 +18,8 @@ pub enum RateLimitError {
     /// IP-specific rate limit exceeded.
     #[error("IP rate limit exceeded")]
     IpLimitExceeded {
+        /// The configured quota for the window that was exceeded.
+        limit: u32,
         /// Time to wait before retrying.
         retry_after: Duration,
     },
@@ -25,6 +29,8 @@ pub enum RateLimitError {
     ServerLimitExceeded {
         /// Name of the server that exceeded the limit.
         server: String,
+        /// The configured quota for the window that was exceeded.
+        limit: u32,
         /// Time to wait before retrying.
         retry_after: Duration,
     },
@@ -36,6 +42,20 @@ pub enum RateLimitError {
         server: String,
         /// Name of the tool.
         tool: String,
+        /// The configured quota for the window that was exceeded.
+        limit: u32,
+        /// Time to wait before retrying.
+        retry_after: Duration,
+    },
+
+    /// Identity-specific rate limit exceeded, resolved dynamically via a
+    /// configured resolver rather than static configuration.
+    #[error("Rate limit exceeded for identity {identity}")]
+    IdentityLimitExceeded {
+        /// Identity that exceeded the limit.
+        identity: String,
+        /// The configured quota for the window that was exceeded.
+        limit: u32,
         /// Time to wait before retrying.
         retry_after: Duration,
     },
@@ -49,10 +69,26 @@ impl RateLimitError {
     /// Get the retry-after duration if available.
     pub fn retry_after(&self) -> Option<Duration> {
         match self {
-            Self::GlobalLimitExceeded { retry_after } => Some(*retry_after),
-            Self::IpLimitExceeded { retry_after } => Some(*retry_after),
+            Self::GlobalLimitExceeded { retry_after, .. } => Some(*retry_after),
+            Self::IpLimitExceeded { retry_after, .. } => Some(*retry_after),
             Self::ServerLimitExceeded { retry_after, .. } => Some(*retry_after),
             Self::ToolLimitExceeded { retry_after, .. } => Some(*retry_after),
+            Self::IdentityLimitExceeded { retry_after, .. } => Some(*retry_after),
+            Self::Storage(_) => None,
+        }
+    }
+
+    /// Get the configured quota for the window that was exceeded, if available.
+    ///
+    /// This is the same quota used to compute the `rate_limit_exceeded` metric,
+    /// so it's safe to surface verbatim in a `RateLimit-Limit` response header.
+    pub fn limit(&self) -> Option<u32> {
+        match self {
+            Self::GlobalLimitExceeded { limit, .. } => Some(*limit),
+            Self::IpLimitExceeded { limit, .. } => Some(*limit),
+            Self::ServerLimitExceeded { limit, .. } => Some(*limit),
+            Self::ToolLimitExceeded { limit, .. } => Some(*limit),
+            Self::IdentityLimitExceeded { limit, .. } => Some(*limit),
             Self::Storage(_) => None,
         }
     }
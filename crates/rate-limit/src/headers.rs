@@ -0,0 +1,97 @@
+//! Support for the IETF "RateLimit header fields for HTTP" draft, emitted on
+//! throttled responses when `[server.rate_limits] response_headers` is set.
+//!
+//! Nexus enforces rate limits at two layers: the HTTP middleware
+//! (global/per-IP/per-identity) and, deeper inside MCP tool-call handling
+//! (per-server/per-tool), past the point where the HTTP layer still has a
+//! response to attach headers to. [`RateLimitDecisionSlot`] lets the deeper
+//! check hand its decision back to the HTTP layer via request extensions,
+//! the same way trace context and baggage already cross that boundary.
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::RateLimitError;
+
+/// The values needed to render `RateLimit-Limit`/`RateLimit-Remaining`/`RateLimit-Reset`
+/// on a throttled response, taken from the same limiter state that produced
+/// the `rate_limit_exceeded` metric.
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimitHeaderInfo {
+    /// The configured quota for the window that was exceeded.
+    pub limit: u32,
+    /// Time to wait before the window refills.
+    pub retry_after: Duration,
+}
+
+impl RateLimitHeaderInfo {
+    /// Extract header info from a rate limit error, if it carries one.
+    /// Returns `None` for errors without quota/retry information (currently
+    /// only [`RateLimitError::Storage`]).
+    pub fn from_error(error: &RateLimitError) -> Option<Self> {
+        Some(Self {
+            limit: error.limit()?,
+            retry_after: error.retry_after()?,
+        })
+    }
+
+    /// Encode this decision into the shape stashed in a `-32000` JSON-RPC error's `data`
+    /// field, so a Nexus instance proxying a downstream Nexus's own rate limit rejection
+    /// can recover it via [`Self::from_downstream_error_data`] and forward it on its own
+    /// response headers instead of discarding it.
+    pub fn to_error_data(self) -> serde_json::Value {
+        serde_json::to_value(RateLimitErrorData {
+            limit: self.limit,
+            retry_after_secs: self.retry_after.as_secs_f64().ceil() as u64,
+        })
+        .expect("RateLimitErrorData always serializes")
+    }
+
+    /// Recover header info from a downstream `-32000` error's `data` field, if it carries
+    /// the shape produced by [`Self::to_error_data`]. Returns `None` for errors from
+    /// downstreams that don't surface retry info this way (e.g. non-Nexus MCP servers).
+    pub fn from_downstream_error_data(data: Option<&serde_json::Value>) -> Option<Self> {
+        let data: RateLimitErrorData = serde_json::from_value(data?.clone()).ok()?;
+
+        Some(Self {
+            limit: data.limit,
+            retry_after: Duration::from_secs(data.retry_after_secs),
+        })
+    }
+}
+
+/// Wire shape of [`RateLimitHeaderInfo`] as stashed in a rate limit error's JSON-RPC `data`
+/// field. Kept separate from `RateLimitHeaderInfo` itself since `Duration` doesn't round-trip
+/// through JSON the way we want (whole seconds, not a `{secs, nanos}` struct).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct RateLimitErrorData {
+    limit: u32,
+    retry_after_secs: u64,
+}
+
+/// Shared slot inserted into request extensions by the HTTP rate limit layer so that a
+/// rejection decided deeper inside MCP tool-call handling - past the point where the HTTP
+/// layer still has a response to attach headers to - can be read back once the request
+/// finishes and turned into response headers.
+#[derive(Debug, Clone, Default)]
+pub struct RateLimitDecisionSlot(Arc<Mutex<Option<RateLimitHeaderInfo>>>);
+
+impl RateLimitDecisionSlot {
+    /// Create an empty slot.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a rate limit decision, to be read back by the HTTP layer once
+    /// request handling completes.
+    pub fn set(&self, info: RateLimitHeaderInfo) {
+        *self.0.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(info);
+    }
+
+    /// Read back the recorded decision, if any was made.
+    pub fn get(&self) -> Option<RateLimitHeaderInfo> {
+        *self.0.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
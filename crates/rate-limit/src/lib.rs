@@ -5,18 +5,24 @@
 //! - Per-IP rate limits
 //! - Per-MCP-server and per-tool rate limits
 //!
-//! Currently supports in-memory storage using the governor crate.
-//! Redis support will be added in future versions.
+//! Supports in-memory storage (the default, backed by the governor crate) as
+//! well as Redis, Redis Sentinel, and Redis Cluster storage, so that quotas
+//! are shared across horizontally scaled Nexus replicas instead of each one
+//! enforcing its own. See [`config::StorageFailureMode`] for how a shared
+//! backend's own failures (as opposed to a legitimately exceeded limit) are handled.
 
 #![deny(missing_docs)]
 
 mod error;
+mod headers;
 mod manager;
 mod request;
+mod resolver;
 mod storage;
 mod token;
 
 pub use error::RateLimitError;
+pub use headers::{RateLimitDecisionSlot, RateLimitHeaderInfo};
 pub use manager::RateLimitManager;
 pub use request::{RateLimitRequest, RateLimitRequestBuilder};
 pub use storage::{InMemoryStorage, RateLimitStorage, StorageError};
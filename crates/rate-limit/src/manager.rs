@@ -2,16 +2,18 @@
 
 use std::sync::Arc;
 
-use config::{McpConfig, RateLimitConfig, StorageConfig};
+use config::{McpConfig, RateLimitConfig, StorageConfig, StorageFailureMode};
 
 use crate::error::RateLimitError;
 use crate::request::RateLimitRequest;
-use crate::storage::{InMemoryStorage, RateLimitContext, RateLimitResult, RateLimitStorage, StorageError};
+use crate::resolver::RateLimitResolver;
+use crate::storage::{InMemoryStorage, MockStorage, RateLimitContext, RateLimitResult, RateLimitStorage, StorageError};
 
 /// Storage backend for rate limiting.
 enum Storage {
     Memory(InMemoryStorage),
     Redis(crate::storage::redis::RedisStorage),
+    Mock(MockStorage),
 }
 
 impl Storage {
@@ -24,6 +26,7 @@ impl Storage {
         match self {
             Storage::Memory(storage) => storage.check_and_consume(context, limit, interval).await,
             Storage::Redis(storage) => storage.check_and_consume(context, limit, interval).await,
+            Storage::Mock(storage) => storage.check_and_consume(context, limit, interval).await,
         }
     }
 }
@@ -37,6 +40,7 @@ pub struct RateLimitInner {
     config: RateLimitConfig,
     mcp_config: McpConfig,
     storage: Storage,
+    resolver: Option<RateLimitResolver>,
 }
 
 impl RateLimitManager {
@@ -49,47 +53,151 @@ impl RateLimitManager {
                 let redis_storage = RedisStorage::new(redis_config).await.map_err(RateLimitError::Storage)?;
                 Storage::Redis(redis_storage)
             }
+            StorageConfig::Sentinel(sentinel_config) => {
+                use crate::storage::redis::RedisStorage;
+                let redis_storage = RedisStorage::new_sentinel(sentinel_config)
+                    .await
+                    .map_err(RateLimitError::Storage)?;
+                Storage::Redis(redis_storage)
+            }
+            StorageConfig::Cluster(cluster_config) => {
+                use crate::storage::redis::RedisStorage;
+                let redis_storage = RedisStorage::new_cluster(cluster_config)
+                    .await
+                    .map_err(RateLimitError::Storage)?;
+                Storage::Redis(redis_storage)
+            }
+            StorageConfig::Mock => Storage::Mock(MockStorage::new()),
         };
 
+        let resolver = config.resolver.as_ref().map(RateLimitResolver::new);
+
         let inner = Arc::new(RateLimitInner {
             config,
             mcp_config,
             storage,
+            resolver,
         });
 
         Ok(Self { inner })
     }
 
+    /// The clock backing this manager's storage, if it was configured with
+    /// the in-process mock backend. Tests can use this to advance time
+    /// deterministically instead of sleeping real seconds; returns `None` for
+    /// every other backend.
+    pub fn mock_clock(&self) -> Option<crate::storage::MockClock> {
+        match &self.inner.storage {
+            Storage::Mock(storage) => Some(storage.clock()),
+            _ => None,
+        }
+    }
+
+    /// Makes the in-process mock storage backend (if configured) fail every
+    /// subsequent call, simulating an unreachable Redis backend so tests can
+    /// exercise `on_storage_error` handling without a live Redis instance.
+    /// No-op for every other backend.
+    pub fn set_mock_storage_failing(&self, failing: bool) {
+        if let Storage::Mock(storage) = &self.inner.storage {
+            storage.set_failing(failing);
+        }
+    }
+
     /// Check all applicable rate limits for a request.
     ///
-    /// This checks in order: global, per-IP, per-server, per-tool.
-    /// Returns an error with the first limit that is exceeded.
+    /// This checks in order: per-identity (if the resolver has a quota for
+    /// this request's identity), otherwise global and per-IP, then
+    /// per-server and per-tool. Returns an error with the first limit that
+    /// is exceeded.
     pub async fn check_request(&self, request: &RateLimitRequest) -> Result<(), RateLimitError> {
         if !self.inner.config.enabled {
             return Ok(());
         }
 
-        self.check_global_limit().await?;
-        self.check_ip_limit(request).await?;
+        if !self.check_identity_limit(request).await? {
+            self.check_global_limit().await?;
+            self.check_ip_limit(request).await?;
+        }
+
         self.check_server_tool_limit(request).await?;
 
         Ok(())
     }
 
+    /// Check and consume quota from the configured storage backend.
+    ///
+    /// A storage-level failure (e.g. Redis unreachable) is distinct from the
+    /// limit being legitimately exceeded, so it's handled per the configured
+    /// [`StorageFailureMode`]: fail closed (the default) propagates the error,
+    /// which ultimately rejects the request the same as an exceeded limit;
+    /// fail open logs the error and treats the request as allowed.
+    async fn check_and_consume(
+        &self,
+        context: &RateLimitContext<'_>,
+        limit: u32,
+        interval: std::time::Duration,
+    ) -> Result<RateLimitResult, RateLimitError> {
+        match self.inner.storage.check_and_consume(context, limit, interval).await {
+            Ok(result) => Ok(result),
+            Err(e) if self.inner.config.on_storage_error == StorageFailureMode::FailOpen => {
+                log::warn!("Rate limit storage error, failing open for {context:?}: {e}");
+
+                Ok(RateLimitResult {
+                    allowed: true,
+                    retry_after: None,
+                })
+            }
+            Err(e) => Err(RateLimitError::Storage(e)),
+        }
+    }
+
+    /// Check the dynamic per-identity rate limit, if a resolver is
+    /// configured and the request carries an identity it resolves a quota
+    /// for.
+    ///
+    /// Returns `Ok(true)` when a quota was resolved and the request is
+    /// within it, so callers can skip the static global/per-IP checks.
+    /// Returns `Ok(false)` when no resolver is configured, the request has
+    /// no identity, or the resolver has no quota for this identity - in
+    /// which case the static rules apply as usual.
+    async fn check_identity_limit(&self, request: &RateLimitRequest) -> Result<bool, RateLimitError> {
+        let Some(resolver) = &self.inner.resolver else {
+            return Ok(false);
+        };
+
+        let Some(identity) = &request.identity else {
+            return Ok(false);
+        };
+
+        let Some(quota) = resolver.resolve(identity).await else {
+            return Ok(false);
+        };
+
+        let context = RateLimitContext::PerIdentity { identity };
+        let result = self.check_and_consume(&context, quota.limit, quota.duration).await?;
+
+        if !result.allowed {
+            return Err(RateLimitError::IdentityLimitExceeded {
+                identity: identity.clone(),
+                limit: quota.limit,
+                retry_after: result.retry_after.unwrap_or_default(),
+            });
+        }
+
+        Ok(true)
+    }
+
     async fn check_global_limit(&self) -> Result<(), RateLimitError> {
         let Some(quota) = &self.inner.config.global else {
             return Ok(());
         };
 
         let context = RateLimitContext::Global;
-        let result = self
-            .inner
-            .storage
-            .check_and_consume(&context, quota.limit, quota.interval)
-            .await?;
+        let result = self.check_and_consume(&context, quota.limit, quota.duration).await?;
 
         if !result.allowed {
             return Err(RateLimitError::GlobalLimitExceeded {
+                limit: quota.limit,
                 retry_after: result.retry_after.unwrap_or_default(),
             });
         }
@@ -107,14 +215,11 @@ impl RateLimitManager {
         };
 
         let context = RateLimitContext::PerIp { ip };
-        let result = self
-            .inner
-            .storage
-            .check_and_consume(&context, quota.limit, quota.interval)
-            .await?;
+        let result = self.check_and_consume(&context, quota.limit, quota.duration).await?;
 
         if !result.allowed {
             return Err(RateLimitError::IpLimitExceeded {
+                limit: quota.limit,
                 retry_after: result.retry_after.unwrap_or_default(),
             });
         }
@@ -171,7 +276,7 @@ impl RateLimitManager {
 
         log::debug!("Evaluating rate limit: context={context:?}, quota={limit} requests per {interval:?}");
 
-        let result = self.inner.storage.check_and_consume(&context, limit, interval).await?;
+        let result = self.check_and_consume(&context, limit, interval).await?;
 
         log::debug!(
             "Rate limit decision: {} (retry after: {:?})",
@@ -184,10 +289,12 @@ impl RateLimitManager {
                 Some(tool_name) => Err(RateLimitError::ToolLimitExceeded {
                     server: server_name.to_string(),
                     tool: tool_name.to_string(),
+                    limit,
                     retry_after: result.retry_after.unwrap_or_default(),
                 }),
                 None => Err(RateLimitError::ServerLimitExceeded {
                     server: server_name.to_string(),
+                    limit,
                     retry_after: result.retry_after.unwrap_or_default(),
                 }),
             }
@@ -196,3 +303,46 @@ impl RateLimitManager {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use config::RateLimitQuota;
+
+    async fn manager_with(on_storage_error: StorageFailureMode) -> RateLimitManager {
+        let config = RateLimitConfig {
+            enabled: true,
+            storage: StorageConfig::Mock,
+            global: Some(RateLimitQuota {
+                limit: 10,
+                duration: std::time::Duration::from_secs(60),
+            }),
+            per_ip: None,
+            resolver: None,
+            response_headers: Default::default(),
+            on_storage_error,
+        };
+
+        RateLimitManager::new(config, McpConfig::default()).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn fail_closed_propagates_storage_error() {
+        let manager = manager_with(StorageFailureMode::FailClosed).await;
+        manager.set_mock_storage_failing(true);
+
+        let request = RateLimitRequest::builder().build();
+        let err = manager.check_request(&request).await.unwrap_err();
+
+        assert!(matches!(err, RateLimitError::Storage(_)));
+    }
+
+    #[tokio::test]
+    async fn fail_open_allows_request_through_storage_error() {
+        let manager = manager_with(StorageFailureMode::FailOpen).await;
+        manager.set_mock_storage_failing(true);
+
+        let request = RateLimitRequest::builder().build();
+        manager.check_request(&request).await.unwrap();
+    }
+}
@@ -11,6 +11,9 @@ pub struct RateLimitRequest {
     pub server_name: Option<String>,
     /// Name of the tool being invoked.
     pub tool_name: Option<String>,
+    /// Caller identity (API token, client ID, or authenticated subject) used
+    /// to resolve a dynamic per-identity rate limit, if one is configured.
+    pub identity: Option<String>,
 }
 
 impl RateLimitRequest {
@@ -26,6 +29,7 @@ pub struct RateLimitRequestBuilder {
     ip: Option<IpAddr>,
     server_name: Option<String>,
     tool_name: Option<String>,
+    identity: Option<String>,
 }
 
 impl RateLimitRequestBuilder {
@@ -59,13 +63,20 @@ impl RateLimitRequestBuilder {
         self.tool_name = Some(tool.into());
         self
     }
-    
+
+    /// Set the caller identity.
+    pub fn identity(mut self, identity: impl Into<String>) -> Self {
+        self.identity = Some(identity.into());
+        self
+    }
+
     /// Build the rate limit request.
     pub fn build(self) -> RateLimitRequest {
         RateLimitRequest {
             ip: self.ip,
             server_name: self.server_name,
             tool_name: self.tool_name,
+            identity: self.identity,
         }
     }
 }
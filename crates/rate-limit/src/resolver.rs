@@ -0,0 +1,103 @@
+//! Dynamic per-identity rate limit resolution.
+
+use std::time::Duration;
+
+use config::{ExternalResolverConfig, RateLimitQuota, RateLimitResolverConfig, StaticResolverConfig};
+use mini_moka::sync::Cache;
+
+/// Default cache capacity for external resolver lookups.
+const DEFAULT_CACHE_CAPACITY: u64 = 10_000;
+
+/// Resolves a rate limit quota for a caller identity, either from a static
+/// map configured ahead of time or by querying an external HTTP endpoint.
+pub(crate) enum RateLimitResolver {
+    /// Identity -> quota map, known ahead of time.
+    Static(StaticResolverConfig),
+    /// External HTTP lookup, cached in-memory per identity.
+    External(ExternalResolver),
+}
+
+impl RateLimitResolver {
+    /// Build a resolver from configuration.
+    pub(crate) fn new(config: &RateLimitResolverConfig) -> Self {
+        match config {
+            RateLimitResolverConfig::Static(static_config) => Self::Static(static_config.clone()),
+            RateLimitResolverConfig::External(external_config) => Self::External(ExternalResolver::new(external_config)),
+        }
+    }
+
+    /// Resolve the quota for `identity`, if one applies.
+    pub(crate) async fn resolve(&self, identity: &str) -> Option<RateLimitQuota> {
+        match self {
+            Self::Static(config) => config.limits.get(identity).cloned(),
+            Self::External(resolver) => resolver.resolve(identity).await,
+        }
+    }
+}
+
+/// External HTTP-backed resolver. Both hits and misses are cached so a burst
+/// of requests from the same identity doesn't hammer the backing endpoint.
+pub(crate) struct ExternalResolver {
+    url: String,
+    client: reqwest::Client,
+    cache: Cache<String, Option<RateLimitQuota>>,
+}
+
+/// Response body expected from the external resolver endpoint.
+#[derive(Debug, serde::Deserialize)]
+struct ResolverResponse {
+    limit: u32,
+    duration_secs: u64,
+}
+
+impl ExternalResolver {
+    fn new(config: &ExternalResolverConfig) -> Self {
+        let cache = Cache::builder()
+            .max_capacity(DEFAULT_CACHE_CAPACITY)
+            .time_to_live(config.cache_ttl)
+            .build();
+
+        Self {
+            url: config.url.clone(),
+            client: reqwest::Client::new(),
+            cache,
+        }
+    }
+
+    async fn resolve(&self, identity: &str) -> Option<RateLimitQuota> {
+        if let Some(cached) = self.cache.get(identity) {
+            return cached;
+        }
+
+        let quota = self.fetch(identity).await;
+        self.cache.insert(identity.to_string(), quota.clone());
+
+        quota
+    }
+
+    async fn fetch(&self, identity: &str) -> Option<RateLimitQuota> {
+        let response = self
+            .client
+            .get(&self.url)
+            .query(&[("identity", identity)])
+            .send()
+            .await
+            .inspect_err(|error| log::warn!("Failed to query rate limit resolver at {}: {error}", self.url))
+            .ok()?;
+
+        if !response.status().is_success() {
+            return None;
+        }
+
+        let body = response
+            .json::<ResolverResponse>()
+            .await
+            .inspect_err(|error| log::warn!("Invalid response from rate limit resolver at {}: {error}", self.url))
+            .ok()?;
+
+        Some(RateLimitQuota {
+            limit: body.limit,
+            duration: Duration::from_secs(body.duration_secs),
+        })
+    }
+}
@@ -199,6 +199,10 @@ impl InMemoryStorage {
                 let key: Cow<'a, str> = Cow::Owned(format!("server:{server}:tool:{tool}"));
                 (key.clone(), key)
             }
+            RateLimitContext::PerIdentity { identity } => {
+                let key: Cow<'a, str> = Cow::Owned(format!("identity:{identity}"));
+                (key.clone(), key)
+            }
         }
     }
 
@@ -0,0 +1,247 @@
+//! In-process mock of the Redis averaging-window algorithm.
+//!
+//! Mirrors the semantics of [`super::redis::RedisStorage`]'s averaging fixed
+//! window exactly, but keeps counters in a `Mutex<HashMap>` instead of Redis
+//! and reads time from a [`MockClock`] that tests can advance deterministically,
+//! rather than depending on wall-clock sleeps. Intended for tests only.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use super::{RateLimitContext, RateLimitResult, RateLimitStorage, StorageError, TokenRateLimitContext};
+
+/// A clock whose current time can be advanced manually, so window-expiry
+/// tests don't have to sleep for real seconds.
+#[derive(Debug, Clone)]
+pub struct MockClock(Arc<AtomicU64>);
+
+impl MockClock {
+    /// Create a clock starting at the current wall-clock time.
+    pub fn new() -> Self {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+
+        Self(Arc::new(AtomicU64::new(now)))
+    }
+
+    /// Advance the clock by `duration`, truncated to whole seconds - the
+    /// resolution the averaging window algorithm operates at.
+    pub fn advance(&self, duration: Duration) {
+        self.0.fetch_add(duration.as_secs(), Ordering::SeqCst);
+    }
+
+    fn now_secs(&self) -> u64 {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Current and previous window counts for a single rate limit key.
+struct WindowCounts {
+    bucket: u64,
+    current: u64,
+    previous: u64,
+}
+
+/// In-process mock storage implementing the same atomic increment/window/expiry
+/// semantics as [`super::redis::RedisStorage`]'s averaging fixed window, so
+/// integration tests can exercise production limiter behavior without a live
+/// Redis instance and without sleeping real time to observe window expiry.
+pub struct MockStorage {
+    clock: MockClock,
+    windows: Mutex<HashMap<String, WindowCounts>>,
+    failing: AtomicBool,
+}
+
+impl MockStorage {
+    /// Create a new mock storage instance, with its own independently
+    /// controllable clock.
+    pub fn new() -> Self {
+        Self {
+            clock: MockClock::new(),
+            windows: Mutex::new(HashMap::new()),
+            failing: AtomicBool::new(false),
+        }
+    }
+
+    /// The clock backing this storage's time-window calculations. Clone and
+    /// hold onto this to advance time deterministically in tests.
+    pub fn clock(&self) -> MockClock {
+        self.clock.clone()
+    }
+
+    /// Makes every subsequent call return a [`StorageError::Connection`],
+    /// simulating an unreachable Redis backend so tests can exercise
+    /// `on_storage_error` handling without a live Redis instance.
+    pub fn set_failing(&self, failing: bool) {
+        self.failing.store(failing, Ordering::SeqCst);
+    }
+
+    fn check_and_consume_inner(&self, key: &str, amount: u64, limit: u32, interval: Duration) -> RateLimitResult {
+        let window_size = interval.as_secs().max(1);
+        let now = self.clock.now_secs();
+        let bucket = now / window_size;
+        let bucket_percentage = (now % window_size) as f64 / window_size as f64;
+
+        let mut windows = self.windows.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let counts = windows.entry(key.to_string()).or_insert_with(|| WindowCounts {
+            bucket,
+            current: 0,
+            previous: 0,
+        });
+
+        // Roll the window forward. A key untouched for more than one interval
+        // has no meaningful "previous" count to carry over, same as the Redis
+        // previous-window key simply expiring.
+        if counts.bucket != bucket {
+            counts.previous = if counts.bucket + 1 == bucket { counts.current } else { 0 };
+            counts.current = 0;
+            counts.bucket = bucket;
+        }
+
+        // Averaging fixed window: weight the previous window's count by how
+        // much of it is still "in view", exactly as the Redis Lua script does.
+        let weighted = counts.previous as f64 * (1.0 - bucket_percentage) + counts.current as f64;
+
+        if weighted + amount as f64 > limit as f64 {
+            let window_end = (bucket + 1) * window_size;
+            let retry_after = Duration::from_secs(window_end.saturating_sub(now));
+
+            return RateLimitResult {
+                allowed: false,
+                retry_after: Some(retry_after),
+            };
+        }
+
+        counts.current += amount;
+
+        RateLimitResult {
+            allowed: true,
+            retry_after: None,
+        }
+    }
+}
+
+impl Default for MockStorage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RateLimitStorage for MockStorage {
+    async fn check_and_consume(
+        &self,
+        context: &RateLimitContext<'_>,
+        limit: u32,
+        interval: Duration,
+    ) -> Result<RateLimitResult, StorageError> {
+        if self.failing.load(Ordering::SeqCst) {
+            return Err(StorageError::Connection("mock storage configured to fail".to_string()));
+        }
+
+        let key = match context {
+            RateLimitContext::Global => "global".to_string(),
+            RateLimitContext::PerIp { ip } => format!("ip:{ip}"),
+            RateLimitContext::PerServer { server } => format!("server:{server}"),
+            RateLimitContext::PerTool { server, tool } => format!("server:{server}:tool:{tool}"),
+            RateLimitContext::PerIdentity { identity } => format!("identity:{identity}"),
+        };
+
+        Ok(self.check_and_consume_inner(&key, 1, limit, interval))
+    }
+
+    async fn check_and_consume_tokens(
+        &self,
+        context: &TokenRateLimitContext<'_>,
+        tokens: u32,
+        limit: u32,
+        interval: Duration,
+    ) -> Result<RateLimitResult, StorageError> {
+        if self.failing.load(Ordering::SeqCst) {
+            return Err(StorageError::Connection("mock storage configured to fail".to_string()));
+        }
+
+        let key = format!(
+            "token:{}:{}:{}:{}",
+            context.client_id,
+            context.group.unwrap_or("default"),
+            context.provider,
+            context.model.unwrap_or("default")
+        );
+
+        Ok(self.check_and_consume_inner(&key, tokens as u64, limit, interval))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn allows_requests_under_the_limit() {
+        let storage = MockStorage::new();
+        let context = RateLimitContext::Global;
+
+        for _ in 0..3 {
+            let result = storage.check_and_consume(&context, 3, Duration::from_secs(60)).await.unwrap();
+            assert!(result.allowed);
+        }
+    }
+
+    #[tokio::test]
+    async fn blocks_requests_over_the_limit() {
+        let storage = MockStorage::new();
+        let context = RateLimitContext::Global;
+
+        for _ in 0..3 {
+            storage.check_and_consume(&context, 3, Duration::from_secs(60)).await.unwrap();
+        }
+
+        let result = storage.check_and_consume(&context, 3, Duration::from_secs(60)).await.unwrap();
+
+        assert!(!result.allowed);
+        assert!(result.retry_after.is_some());
+    }
+
+    #[tokio::test]
+    async fn advancing_the_clock_expires_the_window() {
+        let storage = MockStorage::new();
+        let clock = storage.clock();
+        let context = RateLimitContext::Global;
+
+        for _ in 0..3 {
+            storage.check_and_consume(&context, 3, Duration::from_secs(2)).await.unwrap();
+        }
+
+        assert!(!storage.check_and_consume(&context, 3, Duration::from_secs(2)).await.unwrap().allowed);
+
+        // Advancing past two full windows leaves no weighted carry-over from
+        // the previous window, so the limit is available again.
+        clock.advance(Duration::from_secs(5));
+
+        let result = storage.check_and_consume(&context, 3, Duration::from_secs(2)).await.unwrap();
+        assert!(result.allowed);
+    }
+
+    #[tokio::test]
+    async fn different_contexts_are_tracked_independently() {
+        let storage = MockStorage::new();
+
+        let global = RateLimitContext::Global;
+        let per_ip = RateLimitContext::PerIp { ip: "127.0.0.1".parse().unwrap() };
+
+        for _ in 0..2 {
+            storage.check_and_consume(&global, 2, Duration::from_secs(60)).await.unwrap();
+        }
+
+        assert!(!storage.check_and_consume(&global, 2, Duration::from_secs(60)).await.unwrap().allowed);
+        assert!(storage.check_and_consume(&per_ip, 2, Duration::from_secs(60)).await.unwrap().allowed);
+    }
+}
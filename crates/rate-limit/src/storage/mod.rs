@@ -4,10 +4,12 @@ use std::net::IpAddr;
 use std::time::Duration;
 
 pub mod memory;
+pub mod mock;
 pub mod redis;
 pub mod redis_pool;
 
 pub use memory::InMemoryStorage;
+pub use mock::{MockClock, MockStorage};
 
 /// Result type for rate limit checks.
 pub struct RateLimitResult {
@@ -41,6 +43,9 @@ pub enum RateLimitContext<'a> {
     PerServer { server: &'a str },
     /// Per-MCP tool rate limit within a server.
     PerTool { server: &'a str, tool: &'a str },
+    /// Rate limit for a specific caller identity, resolved dynamically via a
+    /// [`crate::resolver::RateLimitResolver`] rather than static configuration.
+    PerIdentity { identity: &'a str },
 }
 
 /// Trait for rate limit storage backends.
@@ -1,4 +1,9 @@
-//! Redis-based rate limit storage using the averaging fixed window algorithm.
+//! Redis-based rate limit storage.
+//!
+//! Supports two algorithms, selected per storage config via
+//! [`config::RateLimitAlgorithm`]: the default averaging fixed window, and
+//! GCRA, a token-bucket variant that spreads allowed requests evenly across
+//! the interval instead of permitting a full burst at window boundaries.
 
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
@@ -7,9 +12,94 @@ use telemetry::metrics::{
     REDIS_COMMAND_DURATION, REDIS_POOL_CONNECTIONS_AVAILABLE, REDIS_POOL_CONNECTIONS_IN_USE, Recorder,
 };
 
-use super::redis_pool::{Pool, create_pool};
+use super::redis_pool::{
+    Manager, Pool, SentinelManager, SentinelPool, create_cluster_connection, create_pool, create_sentinel_pool,
+};
 use super::{RateLimitContext, RateLimitResult, RateLimitStorage, StorageError, TokenRateLimitContext};
-use config::RedisConfig;
+use config::{ClusterConfig, RateLimitAlgorithm, RedisConfig, SentinelConfig};
+use redis::aio::ConnectionLike;
+
+/// A connection checked out from a standalone or Sentinel-backed pool, or
+/// a handle into a shared Redis Cluster connection.
+///
+/// Implementing `ConnectionLike` directly (rather than `Deref`-ing to a
+/// common connection type) lets every backend keep its own native connection
+/// type while every call site still just passes `&mut conn` to `redis::cmd`/
+/// `Script::invoke_async`.
+enum PooledConnection {
+    Standalone(deadpool::managed::Object<Manager>),
+    Sentinel(deadpool::managed::Object<SentinelManager>),
+    Cluster(redis::cluster_async::ClusterConnection),
+}
+
+impl ConnectionLike for PooledConnection {
+    fn req_packed_command<'a>(&'a mut self, cmd: &'a redis::Cmd) -> redis::RedisFuture<'a, redis::Value> {
+        match self {
+            PooledConnection::Standalone(conn) => conn.req_packed_command(cmd),
+            PooledConnection::Sentinel(conn) => conn.req_packed_command(cmd),
+            PooledConnection::Cluster(conn) => conn.req_packed_command(cmd),
+        }
+    }
+
+    fn req_packed_commands<'a>(
+        &'a mut self,
+        cmd: &'a redis::Pipeline,
+        offset: usize,
+        count: usize,
+    ) -> redis::RedisFuture<'a, Vec<redis::Value>> {
+        match self {
+            PooledConnection::Standalone(conn) => conn.req_packed_commands(cmd, offset, count),
+            PooledConnection::Sentinel(conn) => conn.req_packed_commands(cmd, offset, count),
+            PooledConnection::Cluster(conn) => conn.req_packed_commands(cmd, offset, count),
+        }
+    }
+
+    fn get_db(&self) -> i64 {
+        match self {
+            PooledConnection::Standalone(conn) => conn.get_db(),
+            PooledConnection::Sentinel(conn) => conn.get_db(),
+            PooledConnection::Cluster(conn) => conn.get_db(),
+        }
+    }
+}
+
+/// Either a standalone Redis connection pool, one backed by Sentinel-based
+/// master discovery, or a shared Redis Cluster connection. The rest of
+/// [`RedisStorage`] doesn't need to care which one is in use.
+enum ConnectionPool {
+    Standalone(Pool),
+    Sentinel(SentinelPool),
+    Cluster(redis::cluster_async::ClusterConnection),
+}
+
+impl ConnectionPool {
+    async fn get(&self) -> Result<PooledConnection, StorageError> {
+        match self {
+            ConnectionPool::Standalone(pool) => pool
+                .get()
+                .await
+                .map(PooledConnection::Standalone)
+                .map_err(|e| StorageError::Connection(e.to_string())),
+            ConnectionPool::Sentinel(pool) => pool
+                .get()
+                .await
+                .map(PooledConnection::Sentinel)
+                .map_err(|e| StorageError::Connection(e.to_string())),
+            // `ClusterConnection` is itself a cheap, shareable handle onto the
+            // multiplexed per-node connections, so "checking one out" is just a clone.
+            ConnectionPool::Cluster(conn) => Ok(PooledConnection::Cluster(conn.clone())),
+        }
+    }
+
+    /// Pool occupancy, where meaningful; Redis Cluster has no `deadpool` pool to report on.
+    fn status(&self) -> Option<deadpool::Status> {
+        match self {
+            ConnectionPool::Standalone(pool) => Some(pool.status()),
+            ConnectionPool::Sentinel(pool) => Some(pool.status()),
+            ConnectionPool::Cluster(_) => None,
+        }
+    }
+}
 
 /// Lua script for atomic rate limit check and increment.
 /// This script implements the averaging fixed window algorithm atomically.
@@ -19,17 +109,29 @@ const RATE_LIMIT_SCRIPT: &str = include_str!("redis/rate_limit.lua");
 /// This script consumes multiple tokens at once in the averaging fixed window algorithm.
 const RATE_LIMIT_TOKENS_SCRIPT: &str = include_str!("redis/rate_limit_tokens.lua");
 
+/// Lua script implementing the GCRA (token-bucket) algorithm, used for both
+/// request- and token-based rate limiting when [`RateLimitAlgorithm::Gcra`]
+/// is configured.
+const RATE_LIMIT_GCRA_SCRIPT: &str = include_str!("redis/rate_limit_gcra.lua");
+
 /// Redis-based rate limit storage implementation.
 pub struct RedisStorage {
     /// Redis connection pool.
-    pool: Pool,
+    pool: ConnectionPool,
+    /// Whether `pool` is a Redis Cluster connection, in which case every key's
+    /// scope identifier must be wrapped in a `{...}` hash tag so that the
+    /// current- and previous-window keys for one limiter land on the same slot.
+    cluster_mode: bool,
     /// Key prefix for all rate limit keys.
     key_prefix: String,
     /// Response timeout for Redis commands.
     #[allow(dead_code)] // Will be used for timeouts later
     response_timeout: Duration,
+    /// Rate limiting algorithm used when checking and consuming quota.
+    algorithm: RateLimitAlgorithm,
     rate_limit_script: Script,
     rate_limit_tokens_script: Script,
+    rate_limit_gcra_script: Script,
     /// Metrics gauges for connection pool monitoring
     connections_in_use_gauge: opentelemetry::metrics::Gauge<u64>,
     connections_available_gauge: opentelemetry::metrics::Gauge<u64>,
@@ -42,6 +144,62 @@ impl RedisStorage {
         let pool = create_pool(config)
             .map_err(|e| StorageError::Connection(format!("Failed to create Redis connection pool: {e}")))?;
 
+        Self::from_pool(
+            ConnectionPool::Standalone(pool),
+            false,
+            config.key_prefix.clone(),
+            config.response_timeout,
+            config.algorithm,
+        )
+        .await
+    }
+
+    /// Create a new Redis storage instance backed by a Sentinel-monitored master.
+    ///
+    /// The current master is resolved up front via `SENTINEL get-master-addr-by-name`;
+    /// subsequent connection failures trigger automatic re-resolution, so a failover
+    /// promoting a new master is picked up transparently.
+    pub async fn new_sentinel(config: &SentinelConfig) -> Result<Self, StorageError> {
+        let pool = create_sentinel_pool(config)
+            .await
+            .map_err(|e| StorageError::Connection(format!("Failed to create Redis Sentinel connection pool: {e}")))?;
+
+        Self::from_pool(
+            ConnectionPool::Sentinel(pool),
+            false,
+            config.key_prefix.clone(),
+            config.response_timeout,
+            config.algorithm,
+        )
+        .await
+    }
+
+    /// Create a new Redis storage instance backed by a Redis Cluster.
+    ///
+    /// Cluster topology is discovered from the seed `nodes` via `CLUSTER SLOTS`,
+    /// and `MOVED`/`ASK` redirects are followed automatically as it changes.
+    pub async fn new_cluster(config: &ClusterConfig) -> Result<Self, StorageError> {
+        let conn = create_cluster_connection(config)
+            .await
+            .map_err(|e| StorageError::Connection(format!("Failed to connect to Redis Cluster: {e}")))?;
+
+        Self::from_pool(
+            ConnectionPool::Cluster(conn),
+            true,
+            config.key_prefix.clone(),
+            config.response_timeout,
+            config.algorithm,
+        )
+        .await
+    }
+
+    async fn from_pool(
+        pool: ConnectionPool,
+        cluster_mode: bool,
+        key_prefix: Option<String>,
+        response_timeout: Option<Duration>,
+        algorithm: RateLimitAlgorithm,
+    ) -> Result<Self, StorageError> {
         // Test the connection
         let mut conn = pool
             .get()
@@ -49,13 +207,14 @@ impl RedisStorage {
             .map_err(|e| StorageError::Connection(format!("Failed to get Redis connection from pool: {e}")))?;
 
         let _: String = redis::cmd("PING")
-            .query_async(&mut *conn)
+            .query_async(&mut conn)
             .await
             .map_err(|e| StorageError::Connection(format!("Failed to ping Redis server: {e}")))?;
 
         // Use Lua script for atomic check-and-increment
         let rate_limit_script = Script::new(RATE_LIMIT_SCRIPT);
         let rate_limit_tokens_script = Script::new(RATE_LIMIT_TOKENS_SCRIPT);
+        let rate_limit_gcra_script = Script::new(RATE_LIMIT_GCRA_SCRIPT);
 
         // Initialize metrics gauges
         let connections_in_use_gauge = telemetry::metrics::meter()
@@ -67,19 +226,23 @@ impl RedisStorage {
 
         Ok(Self {
             pool,
-            key_prefix: config
-                .key_prefix
-                .clone()
-                .unwrap_or_else(|| "nexus:rate_limit:".to_string()),
-            response_timeout: config.response_timeout.unwrap_or_else(|| Duration::from_secs(1)),
+            cluster_mode,
+            key_prefix: key_prefix.unwrap_or_else(|| "nexus:rate_limit:".to_string()),
+            response_timeout: response_timeout.unwrap_or_else(|| Duration::from_secs(1)),
+            algorithm,
             rate_limit_script,
             rate_limit_tokens_script,
+            rate_limit_gcra_script,
             connections_in_use_gauge,
             connections_available_gauge,
         })
     }
 
     /// Generate Redis keys for the current and previous time windows.
+    ///
+    /// In cluster mode, the scope identifier is wrapped in a `{...}` hash tag so
+    /// the current- and previous-window keys always hash to the same slot,
+    /// keeping the Lua scripts' multi-key access atomic on a single node.
     fn generate_keys(&self, key: &str, interval: Duration) -> (String, String, u64, f64) {
         let now = SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -93,15 +256,76 @@ impl RedisStorage {
         // Calculate how far we are into the current window (0.0 to 1.0)
         let bucket_percentage = (now % window_size) as f64 / window_size as f64;
 
-        let current_key = format!("{}{}:{current_bucket}", self.key_prefix, key);
-        let previous_key = format!("{}{}:{previous_bucket}", self.key_prefix, key);
+        let scoped_key = if self.cluster_mode {
+            format!("{{{key}}}")
+        } else {
+            key.to_string()
+        };
+
+        let current_key = format!("{}{scoped_key}:{current_bucket}", self.key_prefix);
+        let previous_key = format!("{}{scoped_key}:{previous_bucket}", self.key_prefix);
 
         (current_key, previous_key, window_size, bucket_percentage)
     }
 
+    /// Generate the Redis key backing a GCRA "theoretical arrival time", applying
+    /// the same cluster hash-tagging as [`Self::generate_keys`].
+    fn generate_gcra_key(&self, key: &str) -> String {
+        let scoped_key = if self.cluster_mode {
+            format!("{{{key}}}")
+        } else {
+            key.to_string()
+        };
+
+        format!("{}{scoped_key}:gcra", self.key_prefix)
+    }
+
+    /// Check and consume `tokens` tokens for `key` using the GCRA algorithm.
+    async fn check_gcra(&self, key: &str, tokens: u32, limit: u32, interval: Duration) -> Result<RateLimitResult, StorageError> {
+        let gcra_key = self.generate_gcra_key(key);
+
+        let mut conn = self.pool.get().await?;
+        self.record_pool_metrics();
+
+        let mut cmd_recorder = Recorder::new(REDIS_COMMAND_DURATION);
+        cmd_recorder.push_attribute("operation", "check_gcra");
+
+        let result: Vec<i64> = match self
+            .rate_limit_gcra_script
+            .key(&gcra_key)
+            .arg(limit)
+            .arg(interval.as_secs())
+            .arg(tokens)
+            .invoke_async(&mut conn)
+            .await
+        {
+            Ok(result) => {
+                cmd_recorder.push_attribute("status", "success");
+                cmd_recorder.record();
+                result
+            }
+            Err(e) => {
+                cmd_recorder.push_attribute("status", "error");
+                cmd_recorder.push_attribute("error_type", "script_execution");
+                cmd_recorder.record();
+                return Err(StorageError::Query(format!("GCRA rate limit script failed: {e}")));
+            }
+        };
+
+        let allowed = result[0] == 1;
+        let retry_after_ms = result[1] as u64;
+
+        Ok(RateLimitResult {
+            allowed,
+            retry_after: (!allowed).then(|| Duration::from_millis(retry_after_ms)),
+        })
+    }
+
     /// Record pool metrics (connections in use and available)
     fn record_pool_metrics(&self) {
-        let status = self.pool.status();
+        let Some(status) = self.pool.status() else {
+            return;
+        };
 
         // Record connections in use
         self.connections_in_use_gauge
@@ -125,16 +349,17 @@ impl RateLimitStorage for RedisStorage {
             RateLimitContext::PerIp { ip } => format!("ip:{ip}"),
             RateLimitContext::PerServer { server } => format!("server:{server}"),
             RateLimitContext::PerTool { server, tool } => format!("server:{server}:tool:{tool}"),
+            RateLimitContext::PerIdentity { identity } => format!("identity:{identity}"),
         };
 
+        if self.algorithm == RateLimitAlgorithm::Gcra {
+            return self.check_gcra(&key, 1, limit, interval).await;
+        }
+
         let (current_key, previous_key, window_size, bucket_percentage) = self.generate_keys(&key, interval);
 
         // Get connection from pool
-        let mut conn = self
-            .pool
-            .get()
-            .await
-            .map_err(|e| StorageError::Connection(e.to_string()))?;
+        let mut conn = self.pool.get().await?;
 
         // Record pool metrics
         self.record_pool_metrics();
@@ -153,7 +378,7 @@ impl RateLimitStorage for RedisStorage {
             .arg(window_size)
             .arg(expire_time)
             .arg(bucket_percentage)
-            .invoke_async(&mut *conn)
+            .invoke_async(&mut conn)
             .await
         {
             Ok(result) => {
@@ -209,14 +434,14 @@ impl RateLimitStorage for RedisStorage {
             context.model.unwrap_or("default")
         );
 
+        if self.algorithm == RateLimitAlgorithm::Gcra {
+            return self.check_gcra(&key, tokens, limit, interval).await;
+        }
+
         let (current_key, previous_key, window_size, bucket_percentage) = self.generate_keys(&key, interval);
 
         // Get connection from pool
-        let mut conn = self
-            .pool
-            .get()
-            .await
-            .map_err(|e| StorageError::Connection(e.to_string()))?;
+        let mut conn = self.pool.get().await?;
 
         // Record pool metrics
         self.record_pool_metrics();
@@ -237,7 +462,7 @@ impl RateLimitStorage for RedisStorage {
             .arg(window_size)
             .arg(expire_time)
             .arg(bucket_percentage)
-            .invoke_async(&mut *conn)
+            .invoke_async(&mut conn)
             .await
         {
             Ok(result) => {
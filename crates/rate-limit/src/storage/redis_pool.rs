@@ -4,8 +4,9 @@ use std::sync::atomic::{AtomicUsize, Ordering};
 
 use deadpool::managed::{self, Metrics};
 use redis::{Client, RedisError, RedisResult, aio::MultiplexedConnection};
+use tokio::sync::RwLock;
 
-use config::{RedisConfig, RedisTlsConfig};
+use config::{ClusterConfig, RedisConfig, RedisTlsConfig, SentinelConfig};
 
 /// Redis connection pool.
 pub type Pool = deadpool::managed::Pool<Manager>;
@@ -64,6 +65,175 @@ impl managed::Manager for Manager {
     }
 }
 
+/// Redis connection pool backed by a Sentinel-monitored master.
+pub type SentinelPool = deadpool::managed::Pool<SentinelManager>;
+
+/// Pool manager that resolves the current master address via Sentinel before
+/// connecting, and transparently re-resolves when the previously-resolved
+/// master stops responding (e.g. because it was demoted by a failover).
+#[derive(Debug)]
+pub struct SentinelManager {
+    config: SentinelConfig,
+    inner: RwLock<Manager>,
+}
+
+impl SentinelManager {
+    /// Create a new Sentinel-aware pool manager, resolving the master once up front.
+    pub async fn new(config: SentinelConfig) -> RedisResult<Self> {
+        let redis_config = resolve_master(&config).await?;
+        let inner = Manager::new(&redis_config)?;
+
+        Ok(Self {
+            config,
+            inner: RwLock::new(inner),
+        })
+    }
+
+    /// Re-resolve the current master via Sentinel and swap in a fresh inner manager.
+    async fn reconnect(&self) -> RedisResult<()> {
+        let redis_config = resolve_master(&self.config).await?;
+        let manager = Manager::new(&redis_config)?;
+
+        *self.inner.write().await = manager;
+
+        Ok(())
+    }
+}
+
+impl managed::Manager for SentinelManager {
+    type Type = MultiplexedConnection;
+    type Error = RedisError;
+
+    async fn create(&self) -> Result<MultiplexedConnection, Self::Error> {
+        let first_attempt = self.inner.read().await.create().await;
+
+        match first_attempt {
+            Ok(conn) => Ok(conn),
+            Err(_) => {
+                // The previously-resolved master may have been demoted; re-resolve and retry once.
+                self.reconnect().await?;
+                self.inner.read().await.create().await
+            }
+        }
+    }
+
+    async fn recycle(&self, conn: &mut MultiplexedConnection, metrics: &Metrics) -> managed::RecycleResult<Self::Error> {
+        let result = self.inner.read().await.recycle(conn, metrics).await;
+
+        if result.is_err() {
+            // A failed health-check PING (e.g. a dropped connection) usually means the
+            // master changed out from under us; re-resolve so the *next* `create` call
+            // connects to the new master. The caller is responsible for dropping
+            // this now-stale connection, which deadpool does automatically when
+            // `recycle` returns an error.
+            self.reconnect().await?;
+        }
+
+        result
+    }
+}
+
+/// Query the configured sentinels, in order, for the current address of the
+/// named master, returning a `RedisConfig` pointed at that address.
+///
+/// The first sentinel that answers successfully wins; sentinels that are
+/// unreachable or don't know about the master are skipped.
+async fn resolve_master(config: &SentinelConfig) -> RedisResult<RedisConfig> {
+    let mut last_error = None;
+
+    for node in &config.nodes {
+        let address = match resolve_master_via_node(node, &config.master_name).await {
+            Ok(address) => address,
+            Err(e) => {
+                last_error = Some(e);
+                continue;
+            }
+        };
+
+        return Ok(RedisConfig {
+            url: format!("redis://{address}"),
+            pool: config.pool.clone(),
+            tls: config.tls.clone(),
+            key_prefix: config.key_prefix.clone(),
+            response_timeout: config.response_timeout,
+            connection_timeout: config.connection_timeout,
+            algorithm: config.algorithm,
+        });
+    }
+
+    Err(last_error.unwrap_or_else(|| {
+        RedisError::from((
+            redis::ErrorKind::ClientError,
+            "No sentinel nodes configured",
+            config.master_name.clone(),
+        ))
+    }))
+}
+
+async fn resolve_master_via_node(node: &config::SentinelNode, master_name: &str) -> RedisResult<String> {
+    let client = Client::open(format!("redis://{}:{}", node.host, node.port))?;
+    let mut conn = client.get_multiplexed_async_connection().await?;
+
+    let (host, port): (String, u16) = redis::cmd("SENTINEL")
+        .arg("get-master-addr-by-name")
+        .arg(master_name)
+        .query_async(&mut conn)
+        .await?;
+
+    Ok(format!("{host}:{port}"))
+}
+
+/// Create a Redis connection pool backed by a Sentinel-monitored master.
+pub async fn create_sentinel_pool(config: &SentinelConfig) -> RedisResult<SentinelPool> {
+    let manager = SentinelManager::new(config.clone()).await?;
+
+    let mut pool_config = deadpool::managed::PoolConfig::default();
+
+    if let Some(max_size) = config.pool.max_size {
+        pool_config.max_size = max_size;
+    }
+
+    if let Some(timeout_create) = config.pool.timeout_create {
+        pool_config.timeouts.create = Some(timeout_create);
+    }
+
+    if let Some(timeout_wait) = config.pool.timeout_wait {
+        pool_config.timeouts.wait = Some(timeout_wait);
+    }
+
+    if let Some(timeout_recycle) = config.pool.timeout_recycle {
+        pool_config.timeouts.recycle = Some(timeout_recycle);
+    }
+
+    let pool = SentinelPool::builder(manager)
+        .config(pool_config)
+        .runtime(deadpool::Runtime::Tokio1)
+        .build()
+        .map_err(|e| RedisError::from((redis::ErrorKind::IoError, "Failed to create pool", e.to_string())))?;
+
+    Ok(pool)
+}
+
+/// Open a connection to a Redis Cluster from its seed node URLs.
+///
+/// Unlike the standalone and Sentinel backends, a `ClusterConnection` is not
+/// pooled through `deadpool`: it already multiplexes requests across every
+/// node it discovers via `CLUSTER SLOTS`, and transparently follows `MOVED`/
+/// `ASK` redirects as the cluster topology changes, so a single shared
+/// connection plays the same role a pool would for the other backends.
+pub async fn create_cluster_connection(config: &ClusterConfig) -> RedisResult<redis::cluster_async::ClusterConnection> {
+    let mut builder = redis::cluster::ClusterClientBuilder::new(config.nodes.clone());
+
+    if let Some(tls_config) = &config.tls {
+        let tls_certs = build_tls_certificates(tls_config)?;
+        builder = builder.certs(tls_certs);
+    }
+
+    let client = builder.build()?;
+
+    client.get_async_connection().await
+}
+
 /// Build TLS certificates from configuration.
 fn build_tls_certificates(config: &RedisTlsConfig) -> RedisResult<redis::TlsCertificates> {
     use redis::ClientTlsConfig;
@@ -3,10 +3,10 @@
 use std::sync::Arc;
 use std::time::Duration;
 
-use config::{PerUserRateLimits, StorageConfig, TokenRateLimit, TokenRateLimitsConfig};
+use config::{Modality, PerUserRateLimits, StorageConfig, StorageFailureMode, TokenRateLimit, TokenRateLimitsConfig};
 
 use crate::error::RateLimitError;
-use crate::storage::{InMemoryStorage, RateLimitStorage, StorageError};
+use crate::storage::{InMemoryStorage, MockStorage, RateLimitStorage, StorageError};
 
 /// Request information for token-based rate limiting.
 #[derive(Debug, Clone)]
@@ -19,14 +19,19 @@ pub struct TokenRateLimitRequest {
     pub provider: String,
     /// Model name (e.g., "gpt-4", "claude-3").
     pub model: Option<String>,
-    /// Number of tokens to consume.
-    pub tokens: usize,
+    /// Content modality of the requested model, determining whether this
+    /// request is metered by input token count or by request count.
+    pub modality: Modality,
+    /// Number of input tokens to consume. Ignored for non-text modalities,
+    /// which are metered per request instead.
+    pub input_tokens: usize,
 }
 
 /// Storage backend for token rate limiting.
 enum Storage {
     Memory(InMemoryStorage),
     Redis(crate::storage::redis::RedisStorage),
+    Mock(MockStorage),
 }
 
 impl Storage {
@@ -40,6 +45,7 @@ impl Storage {
         match self {
             Storage::Memory(storage) => storage.check_and_consume_tokens(key, tokens, limit, interval).await,
             Storage::Redis(storage) => storage.check_and_consume_tokens(key, tokens, limit, interval).await,
+            Storage::Mock(storage) => storage.check_and_consume_tokens(key, tokens, limit, interval).await,
         }
     }
 }
@@ -48,11 +54,12 @@ impl Storage {
 #[derive(Clone)]
 pub struct TokenRateLimitManager {
     storage: Arc<Storage>,
+    on_storage_error: StorageFailureMode,
 }
 
 impl TokenRateLimitManager {
     /// Create a new token rate limit manager with configured storage backend.
-    pub async fn new(storage_config: &StorageConfig) -> Result<Self, RateLimitError> {
+    pub async fn new(storage_config: &StorageConfig, on_storage_error: StorageFailureMode) -> Result<Self, RateLimitError> {
         let storage = match storage_config {
             StorageConfig::Memory => Storage::Memory(InMemoryStorage::new()),
             StorageConfig::Redis(redis_config) => {
@@ -60,13 +67,40 @@ impl TokenRateLimitManager {
                 let redis_storage = RedisStorage::new(redis_config).await.map_err(RateLimitError::Storage)?;
                 Storage::Redis(redis_storage)
             }
+            StorageConfig::Sentinel(sentinel_config) => {
+                use crate::storage::redis::RedisStorage;
+                let redis_storage = RedisStorage::new_sentinel(sentinel_config)
+                    .await
+                    .map_err(RateLimitError::Storage)?;
+                Storage::Redis(redis_storage)
+            }
+            StorageConfig::Cluster(cluster_config) => {
+                use crate::storage::redis::RedisStorage;
+                let redis_storage = RedisStorage::new_cluster(cluster_config)
+                    .await
+                    .map_err(RateLimitError::Storage)?;
+                Storage::Redis(redis_storage)
+            }
+            StorageConfig::Mock => Storage::Mock(MockStorage::new()),
         };
 
         Ok(Self {
             storage: Arc::new(storage),
+            on_storage_error,
         })
     }
 
+    /// The clock backing this manager's storage, if it was configured with
+    /// the in-process mock backend. Tests can use this to advance time
+    /// deterministically instead of sleeping real seconds; returns `None` for
+    /// every other backend.
+    pub fn mock_clock(&self) -> Option<crate::storage::MockClock> {
+        match self.storage.as_ref() {
+            Storage::Mock(storage) => Some(storage.clock()),
+            _ => None,
+        }
+    }
+
     /// Check if a token request is allowed based on rate limits.
     ///
     /// Returns the duration to wait if rate limited, or None if allowed.
@@ -95,28 +129,51 @@ impl TokenRateLimitManager {
             request.model.as_deref().unwrap_or("default")
         );
 
+        // Text models are metered by input token count; non-text models (image, audio)
+        // have no meaningful token count and are metered per request instead.
+        let Some((amount_to_consume, limit)) = limit_for_modality(request.modality, request.input_tokens, &rate_limit)
+        else {
+            log::debug!(
+                "No {:?} rate limit configured for client {}",
+                request.modality,
+                request.client_id
+            );
+            return Ok(None);
+        };
+
         log::debug!(
-            "Checking token rate limit for key '{}': {} tokens against limit of {} per {:?}",
+            "Checking {:?} rate limit for key '{}': {} against limit of {} per {:?}",
+            request.modality,
             key,
-            request.tokens,
-            rate_limit.limit,
+            amount_to_consume,
+            limit,
             rate_limit.interval
         );
 
-        // Check rate limit using the storage backend with token consumption
-        let tokens_to_consume = request.tokens as u32;
-        let token_limit = rate_limit.limit as u32;
-
-        if tokens_to_consume == 0 || token_limit == 0 {
-            // Edge case: no tokens requested or no limit set
+        if amount_to_consume == 0 || limit == 0 {
+            // Edge case: nothing to consume or no limit set
             return Ok(None);
         }
 
-        let result = self
+        // A storage-level failure (e.g. Redis unreachable) is distinct from the limit being
+        // legitimately exceeded, so it's handled per the configured [`StorageFailureMode`],
+        // the same as `RateLimitManager::check_and_consume`.
+        let result = match self
             .storage
-            .check_and_consume_tokens(&key, tokens_to_consume, token_limit, rate_limit.interval)
+            .check_and_consume_tokens(&key, amount_to_consume, limit, rate_limit.interval)
             .await
-            .map_err(RateLimitError::Storage)?;
+        {
+            Ok(result) => result,
+            Err(e) if self.on_storage_error == StorageFailureMode::FailOpen => {
+                log::warn!("Token rate limit storage error, failing open for key '{key}': {e}");
+
+                crate::storage::RateLimitResult {
+                    allowed: true,
+                    retry_after: None,
+                }
+            }
+            Err(e) => return Err(RateLimitError::Storage(e)),
+        };
 
         if !result.allowed {
             // If retry_after is None, it means the request can never succeed (insufficient capacity)
@@ -144,9 +201,20 @@ impl TokenRateLimitManager {
 /// Helper to convert PerUserRateLimits to TokenRateLimit for the default case.
 fn per_user_to_token_limit(per_user: &PerUserRateLimits) -> TokenRateLimit {
     TokenRateLimit {
-        limit: per_user.limit,
+        input_token_limit: per_user.input_token_limit,
+        request_limit: per_user.request_limit,
         interval: per_user.interval,
-        output_buffer: per_user.output_buffer,
+    }
+}
+
+/// Select the amount to consume and the applicable limit for a request's modality.
+///
+/// Returns `None` if the resolved rate limit doesn't configure a limit for this
+/// modality (e.g. an image model hitting a tier that only sets `input_token_limit`).
+fn limit_for_modality(modality: Modality, input_tokens: usize, rate_limit: &TokenRateLimit) -> Option<(u32, u32)> {
+    match modality {
+        Modality::Text => rate_limit.input_token_limit.map(|limit| (input_tokens as u32, limit as u32)),
+        Modality::Image | Modality::Audio => rate_limit.request_limit.map(|limit| (1, limit as u32)),
     }
 }
 
@@ -205,18 +273,18 @@ mod tests {
         let default_limit = default.unwrap_or(1000);
         TokenRateLimitsConfig {
             per_user: Some(PerUserRateLimits {
-                limit: default_limit,
+                input_token_limit: Some(default_limit),
+                request_limit: None,
                 interval: Duration::from_secs(60),
-                output_buffer: Some(500),
                 groups: groups
                     .into_iter()
                     .map(|(name, limit)| {
                         (
                             name.to_string(),
                             TokenRateLimit {
-                                limit,
+                                input_token_limit: Some(limit),
+                                request_limit: None,
                                 interval: Duration::from_secs(60),
-                                output_buffer: Some(500),
                             },
                         )
                     })
@@ -231,7 +299,7 @@ mod tests {
         let model_limits = create_limits(Some(3000), vec![("pro", 4000)]);
 
         let limit = resolve_token_rate_limit(Some("pro"), Some(&provider_limits), Some(&model_limits));
-        assert_eq!(limit.unwrap().limit, 4000); // Model + Group
+        assert_eq!(limit.unwrap().input_token_limit, Some(4000)); // Model + Group
     }
 
     #[test]
@@ -240,7 +308,7 @@ mod tests {
         let model_limits = create_limits(Some(3000), vec![("enterprise", 4000)]);
 
         let limit = resolve_token_rate_limit(Some("pro"), Some(&provider_limits), Some(&model_limits));
-        assert_eq!(limit.unwrap().limit, 3000); // Model default
+        assert_eq!(limit.unwrap().input_token_limit, Some(3000)); // Model default
     }
 
     #[test]
@@ -248,7 +316,7 @@ mod tests {
         let provider_limits = create_limits(Some(1000), vec![("pro", 2000)]);
 
         let limit = resolve_token_rate_limit(Some("pro"), Some(&provider_limits), None);
-        assert_eq!(limit.unwrap().limit, 2000); // Provider + Group
+        assert_eq!(limit.unwrap().input_token_limit, Some(2000)); // Provider + Group
     }
 
     #[test]
@@ -256,7 +324,7 @@ mod tests {
         let provider_limits = create_limits(Some(1000), vec![("enterprise", 2000)]);
 
         let limit = resolve_token_rate_limit(Some("pro"), Some(&provider_limits), None);
-        assert_eq!(limit.unwrap().limit, 1000); // Provider default
+        assert_eq!(limit.unwrap().input_token_limit, Some(1000)); // Provider default
     }
 
     #[test]
@@ -264,4 +332,39 @@ mod tests {
         let limit = resolve_token_rate_limit(Some("pro"), None, None);
         assert!(limit.is_none());
     }
+
+    #[test]
+    fn test_limit_for_modality_text_uses_input_tokens() {
+        let rate_limit = TokenRateLimit {
+            input_token_limit: Some(1000),
+            request_limit: None,
+            interval: Duration::from_secs(60),
+        };
+
+        assert_eq!(limit_for_modality(Modality::Text, 42, &rate_limit), Some((42, 1000)));
+    }
+
+    #[test]
+    fn test_limit_for_modality_image_uses_request_count() {
+        let rate_limit = TokenRateLimit {
+            input_token_limit: None,
+            request_limit: Some(2),
+            interval: Duration::from_secs(60),
+        };
+
+        // Input token count is irrelevant for image models - always consumes 1 request.
+        assert_eq!(limit_for_modality(Modality::Image, 999, &rate_limit), Some((1, 2)));
+    }
+
+    #[test]
+    fn test_limit_for_modality_no_applicable_limit() {
+        let rate_limit = TokenRateLimit {
+            input_token_limit: Some(1000),
+            request_limit: None,
+            interval: Duration::from_secs(60),
+        };
+
+        // A text-only limit configuration doesn't apply to an image request.
+        assert_eq!(limit_for_modality(Modality::Image, 0, &rate_limit), None);
+    }
 }
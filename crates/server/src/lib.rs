@@ -8,7 +8,9 @@ mod auth;
 mod cors;
 mod csrf;
 mod health;
+mod metrics_endpoint;
 mod rate_limit;
+mod tracing;
 mod well_known;
 
 use std::net::SocketAddr;
@@ -36,6 +38,15 @@ pub struct ServeConfig {
 pub async fn serve(ServeConfig { listen_address, config }: ServeConfig) -> anyhow::Result<()> {
     let mut app = Router::new();
 
+    // Initialize telemetry up front so metrics recorded by the rest of this
+    // function (and by every request afterwards) are captured. Keeping the
+    // guard alive for the lifetime of `serve` keeps exporters running; it's
+    // flushed and shut down automatically when the server stops.
+    let telemetry_guard = match &config.telemetry {
+        Some(telemetry_config) => Some(Arc::new(telemetry::init(telemetry_config).await?)),
+        None => None,
+    };
+
     // Create CORS layer first, like Grafbase does
     let cors = if let Some(cors_config) = &config.server.cors {
         cors::generate(cors_config)
@@ -115,7 +126,11 @@ pub async fn serve(ServeConfig { listen_address, config }: ServeConfig) -> anyho
         && let Some(manager) = rate_limit_manager
     {
         log::debug!("Applying HTTP rate limiting middleware to protected routes");
-        protected_router = protected_router.layer(RateLimitLayer::new(manager));
+        protected_router = protected_router.layer(RateLimitLayer::new(
+            manager,
+            config.server.rate_limits.response_headers,
+            config.server.trusted_proxies.clone(),
+        ));
     }
 
     // Merge protected routes (with rate limiting) into main app
@@ -138,11 +153,35 @@ pub async fn serve(ServeConfig { listen_address, config }: ServeConfig) -> anyho
         }
     }
 
+    // Add Prometheus scrape endpoint (unprotected), if configured and a guard exists to scrape from.
+    if let Some(guard) = telemetry_guard.clone()
+        && let Some(prometheus_config) = config.telemetry.as_ref().and_then(|t| t.metrics_prometheus_config()).cloned()
+    {
+        if let Some(listen) = prometheus_config.listen {
+            tokio::spawn(metrics_endpoint::bind_metrics_endpoint(
+                listen,
+                config.server.tls.clone(),
+                prometheus_config,
+                guard,
+            ));
+        } else {
+            app = app.merge(metrics_endpoint::router(&prometheus_config, guard));
+        }
+    }
+
     // Apply CSRF protection to the entire app if enabled
     if config.server.csrf.enabled {
         app = csrf::inject_layer(app, &config.server.csrf);
     }
 
+    // Apply distributed tracing to the entire app, so every request (MCP, LLM, health, ...)
+    // gets a root span, with the configured propagators used to pick up a parent context.
+    if let Some(tracing_config) = config.telemetry.as_ref().map(|t| t.tracing())
+        && tracing_config.enabled
+    {
+        app = app.layer(tracing::TracingLayer::new(tracing_config.propagation.clone()));
+    }
+
     let listener = TcpListener::bind(listen_address)
         .await
         .map_err(|e| anyhow!("Failed to bind to {listen_address}: {e}"))?;
@@ -170,7 +209,7 @@ pub async fn serve(ServeConfig { listen_address, config }: ServeConfig) -> anyho
             }
 
             axum_server::from_tcp_rustls(listener.into_std()?, rustls_config)
-                .serve(app.into_make_service())
+                .serve(app.into_make_service_with_connect_info::<SocketAddr>())
                 .await
                 .map_err(|e| anyhow!("Failed to start HTTPS server: {e}"))?;
         }
@@ -183,7 +222,7 @@ pub async fn serve(ServeConfig { listen_address, config }: ServeConfig) -> anyho
                 log::info!("AI endpoint available at: http://{listen_address}{}", config.llm.path);
             }
 
-            axum::serve(listener, app)
+            axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>())
                 .await
                 .map_err(|e| anyhow!("Failed to start HTTP server: {}", e))?;
         }
@@ -0,0 +1,57 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use axum::{Router, routing::get};
+use config::{PrometheusExporterConfig, TlsServerConfig};
+use http::StatusCode;
+use telemetry::TelemetryGuard;
+
+/// Renders the current metrics snapshot in Prometheus text exposition format.
+async fn scrape(guard: Arc<TelemetryGuard>) -> (StatusCode, String) {
+    match guard.encode_prometheus_metrics() {
+        Ok(body) => (StatusCode::OK, body),
+        Err(e) => {
+            log::error!("Failed to encode Prometheus metrics: {e}");
+            (StatusCode::INTERNAL_SERVER_ERROR, String::new())
+        }
+    }
+}
+
+/// Builds a router exposing the Prometheus scrape endpoint at the configured path.
+pub(super) fn router(config: &PrometheusExporterConfig, guard: Arc<TelemetryGuard>) -> Router {
+    Router::new().route(&config.path, get(move || scrape(guard.clone())))
+}
+
+/// Binds the Prometheus scrape endpoint to the specified address and configuration.
+pub(super) async fn bind_metrics_endpoint(
+    addr: SocketAddr,
+    tls_config: Option<TlsServerConfig>,
+    prometheus_config: PrometheusExporterConfig,
+    guard: Arc<TelemetryGuard>,
+) -> anyhow::Result<()> {
+    let scheme = if tls_config.is_some() { "https" } else { "http" };
+    let path = prometheus_config.path.clone();
+    let app = router(&prometheus_config, guard).into_make_service();
+
+    log::info!("Prometheus scrape endpoint exposed at {scheme}://{addr}{path}");
+
+    match tls_config {
+        Some(tls) => {
+            let rustls_config = axum_server::tls_rustls::RustlsConfig::from_pem_file(&tls.certificate, &tls.key)
+                .await
+                .map_err(|e| anyhow!("Failed to load TLS certificate and key: {}", e))?;
+
+            axum_server::bind_rustls(addr, rustls_config)
+                .serve(app)
+                .await
+                .map_err(|e| anyhow!("Failed to start HTTP server in the metrics endpoint: {e}"))?;
+        }
+        None => axum_server::bind(addr)
+            .serve(app)
+            .await
+            .map_err(|e| anyhow!("Failed to start HTTP server in the metrics endpoint: {e}"))?,
+    }
+
+    Ok(())
+}
@@ -10,18 +10,30 @@ use std::{
 };
 
 use axum::{body::Body, extract::ConnectInfo};
-use http::{Request, Response, StatusCode};
-use rate_limit::{RateLimitError, RateLimitManager, RateLimitRequest};
+use http::{HeaderValue, Request, Response, StatusCode};
+use rate_limit::{RateLimitDecisionSlot, RateLimitError, RateLimitHeaderInfo, RateLimitManager, RateLimitRequest};
 use tower::Layer;
 
-use config::ClientIdentity;
+use config::{ClientIdentity, RateLimitResponseHeaders};
 
 #[derive(Clone)]
-pub struct RateLimitLayer(Arc<RateLimitManager>);
+pub struct RateLimitLayer {
+    manager: Arc<RateLimitManager>,
+    response_headers: RateLimitResponseHeaders,
+    trusted_proxies: Arc<[IpAddr]>,
+}
 
 impl RateLimitLayer {
-    pub fn new(manager: Arc<RateLimitManager>) -> Self {
-        Self(manager)
+    pub fn new(
+        manager: Arc<RateLimitManager>,
+        response_headers: RateLimitResponseHeaders,
+        trusted_proxies: Vec<IpAddr>,
+    ) -> Self {
+        Self {
+            manager,
+            response_headers,
+            trusted_proxies: trusted_proxies.into(),
+        }
     }
 }
 
@@ -34,7 +46,9 @@ where
     fn layer(&self, next: Service) -> Self::Service {
         RateLimitService {
             next,
-            manager: self.0.clone(),
+            manager: self.manager.clone(),
+            response_headers: self.response_headers,
+            trusted_proxies: self.trusted_proxies.clone(),
         }
     }
 }
@@ -43,6 +57,8 @@ where
 pub struct RateLimitService<Service> {
     next: Service,
     manager: Arc<RateLimitManager>,
+    response_headers: RateLimitResponseHeaders,
+    trusted_proxies: Arc<[IpAddr]>,
 }
 
 impl<Service, ReqBody> tower::Service<Request<ReqBody>> for RateLimitService<Service>
@@ -60,13 +76,15 @@ where
         self.next.poll_ready(cx)
     }
 
-    fn call(&mut self, req: Request<ReqBody>) -> Self::Future {
+    fn call(&mut self, mut req: Request<ReqBody>) -> Self::Future {
         let mut next = self.next.clone();
         let manager = self.manager.clone();
+        let response_headers = self.response_headers;
+        let trusted_proxies = self.trusted_proxies.clone();
 
         Box::pin(async move {
             // Extract client IP for IP-based rate limiting
-            let ip = extract_client_ip(&req);
+            let ip = extract_client_ip(&req, &trusted_proxies);
 
             // Get client identity from request extensions (already validated by ClientIdentificationLayer)
             let identity = req.extensions().get::<ClientIdentity>().cloned();
@@ -78,13 +96,16 @@ where
                 builder = builder.ip(ip);
             }
 
-            // Log client identity if present
+            // Log client identity if present, and use it to resolve a dynamic
+            // per-identity rate limit, if one is configured.
             if let Some(ref identity) = identity {
                 log::debug!(
                     "Rate limiting for client: {} in group: {:?}",
                     identity.client_id,
                     identity.group
                 );
+
+                builder = builder.identity(identity.client_id.clone());
             }
 
             let rate_limit_request = builder.build();
@@ -92,8 +113,24 @@ where
             // Check rate limits
             let err = match manager.check_request(&rate_limit_request).await {
                 Ok(()) => {
-                    // Request allowed, continue to next handler
-                    return next.call(req).await;
+                    // This layer only saw global/IP/identity limits - per-server/per-tool limits
+                    // are checked deeper inside MCP tool-call handling, past the point where this
+                    // layer still has a response to attach headers to. Hand it a slot via request
+                    // extensions (the same mechanism used for trace context/baggage) so it can
+                    // still report its decision back here once the request finishes.
+                    let decision_slot = (response_headers != RateLimitResponseHeaders::None).then(|| {
+                        let slot = RateLimitDecisionSlot::new();
+                        req.extensions_mut().insert(slot.clone());
+                        slot
+                    });
+
+                    let mut response = next.call(req).await?;
+
+                    if let Some(info) = decision_slot.and_then(|slot| slot.get()) {
+                        apply_rate_limit_headers(response.headers_mut(), response_headers, info, None);
+                    }
+
+                    return Ok(response);
                 }
                 Err(err) => err,
             };
@@ -107,37 +144,92 @@ where
                 _ => (StatusCode::TOO_MANY_REQUESTS, "Rate limit exceeded"),
             };
 
-            let response = Response::builder()
+            let mut response = Response::builder()
                 .status(status)
                 .header("Content-Type", "text/plain")
                 .body(Body::from(message))
                 .unwrap();
 
-            // No Retry-After headers are sent to maintain consistency with downstream LLM providers
+            // Historically no Retry-After header was sent, to maintain consistency with
+            // downstream LLM providers; `response_headers` opts back into IETF-standard headers.
+            if let Some(info) = RateLimitHeaderInfo::from_error(&err) {
+                apply_rate_limit_headers(response.headers_mut(), response_headers, info, Some(status));
+            }
+
             Ok(response)
         })
     }
 }
 
+/// Apply the configured `RateLimit-*`/`Retry-After` headers to a response, if
+/// `response_headers` isn't `None`. `status` is only used to decide whether
+/// `Retry-After` applies (it's only meaningful alongside a throttling status);
+/// pass `None` when headers are attached to an otherwise-successful response
+/// whose body carries a deeper (e.g. MCP-layer) rate limit rejection.
+fn apply_rate_limit_headers(
+    headers: &mut http::HeaderMap,
+    response_headers: RateLimitResponseHeaders,
+    info: RateLimitHeaderInfo,
+    status: Option<StatusCode>,
+) {
+    if response_headers == RateLimitResponseHeaders::None {
+        return;
+    }
+
+    let reset_secs = info.retry_after.as_secs_f64().ceil() as u64;
+
+    if let Ok(value) = HeaderValue::from_str(&info.limit.to_string()) {
+        headers.insert("RateLimit-Limit", value);
+    }
+
+    headers.insert("RateLimit-Remaining", HeaderValue::from_static("0"));
+
+    if let Ok(value) = HeaderValue::from_str(&reset_secs.to_string()) {
+        headers.insert("RateLimit-Reset", value.clone());
+
+        if status.is_none_or(|status| status == StatusCode::TOO_MANY_REQUESTS) {
+            headers.insert("Retry-After", value);
+        }
+    }
+}
+
 /// Extract client IP address from request.
-fn extract_client_ip<B>(req: &Request<B>) -> Option<IpAddr> {
-    // First try to get from ConnectInfo (direct connection)
-    if let Some(connect_info) = req.extensions().get::<ConnectInfo<SocketAddr>>() {
-        return Some(connect_info.0.ip());
+///
+/// The direct TCP peer address (via [`ConnectInfo`]) is authoritative unless it's in
+/// `trusted_proxies`, in which case the peer is a known reverse proxy/load balancer and
+/// `X-Forwarded-For`/`X-Real-IP` are trusted to recover the real client address instead.
+/// Without a trusted peer, forwarded headers are ignored - otherwise any client could spoof
+/// them to bypass `per_ip` rate limiting.
+fn extract_client_ip<B>(req: &Request<B>, trusted_proxies: &[IpAddr]) -> Option<IpAddr> {
+    let peer_ip = req
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|connect_info| connect_info.0.ip());
+
+    match peer_ip {
+        Some(peer_ip) if !trusted_proxies.contains(&peer_ip) => return Some(peer_ip),
+        _ => {}
     }
 
-    // Try X-Forwarded-For header (for proxied requests)
+    // Peer is a trusted proxy (or unknown) - honor forwarded headers.
     if let Some(forwarded_for) = req.headers().get("x-forwarded-for") {
         let value = forwarded_for.to_str().ok()?;
 
-        // Take the first IP in the chain
+        // Take the first IP in the chain (the original client)
         let ip_str = value.split(',').next()?;
 
-        return ip_str.trim().parse::<IpAddr>().ok();
+        if let Ok(ip) = ip_str.trim().parse::<IpAddr>() {
+            return Some(ip);
+        }
     }
 
     // Try X-Real-IP header
-    let ip_str = req.headers().get("x-real-ip")?.to_str().ok()?;
+    if let Some(ip_str) = req.headers().get("x-real-ip").and_then(|v| v.to_str().ok())
+        && let Ok(ip) = ip_str.parse::<IpAddr>()
+    {
+        return Some(ip);
+    }
 
-    ip_str.parse::<IpAddr>().ok()
+    // Forwarded headers missing or unparseable - fall back to the (trusted-proxy's) peer address.
+    peer_ip
 }
@@ -3,6 +3,7 @@
 //! Creates distributed traces for all HTTP requests following OpenTelemetry semantic conventions.
 
 use axum::{body::Body, extract::MatchedPath};
+use config::PropagationConfig;
 use fastrace::future::FutureExt;
 use fastrace::{
     Span,
@@ -16,21 +17,24 @@ use std::{
     pin::Pin,
     task::{Context, Poll},
 };
+use telemetry::propagation::parse_w3c_baggage;
 use tower::Layer;
 
 /// Layer for HTTP tracing
 #[derive(Clone)]
-pub struct TracingLayer;
+pub struct TracingLayer {
+    propagation: PropagationConfig,
+}
 
 impl TracingLayer {
-    pub fn new() -> Self {
-        Self
+    pub fn new(propagation: PropagationConfig) -> Self {
+        Self { propagation }
     }
 }
 
 impl Default for TracingLayer {
     fn default() -> Self {
-        Self::new()
+        Self::new(PropagationConfig::default())
     }
 }
 
@@ -41,7 +45,10 @@ where
     type Service = TracingService<Service>;
 
     fn layer(&self, next: Service) -> Self::Service {
-        TracingService { next }
+        TracingService {
+            next,
+            propagation: self.propagation.clone(),
+        }
     }
 }
 
@@ -49,6 +56,7 @@ where
 #[derive(Clone)]
 pub struct TracingService<Service> {
     next: Service,
+    propagation: PropagationConfig,
 }
 
 impl<Service, ReqBody> tower::Service<Request<ReqBody>> for TracingService<Service>
@@ -84,8 +92,8 @@ where
             .and_then(|h| h.to_str().ok())
             .map(|s| s.to_string());
 
-        // Extract trace context from headers
-        let span_context = extract_trace_context(req.headers());
+        // Extract trace context from headers, honoring the configured propagators
+        let span_context = extract_trace_context(req.headers(), &self.propagation);
 
         // Create span name
         let span_name = format!("{} {}", method, path);
@@ -107,6 +115,18 @@ where
         // Unfortunately, MCP spans will be siblings rather than children due to this limitation
         req.extensions_mut().insert(parent);
 
+        // Likewise for baggage: extract it once here and hand it to downstream handlers
+        // as extension data, since it can't ride along on the fastrace span itself.
+        if self.propagation.baggage
+            && let Some(baggage) = req
+                .headers()
+                .get("baggage")
+                .and_then(|value| value.to_str().ok())
+                .and_then(parse_w3c_baggage)
+        {
+            req.extensions_mut().insert(baggage);
+        }
+
         // Add span attributes following OpenTelemetry semantic conventions
         root.add_property(|| ("http.request.method", method.clone()));
         root.add_property(|| ("http.route", path.clone()));
@@ -144,10 +164,11 @@ where
     }
 }
 
-/// Extract trace context from HTTP headers
-fn extract_trace_context(headers: &HeaderMap) -> Option<SpanContext> {
+/// Extract trace context from HTTP headers, using whichever propagators are enabled.
+fn extract_trace_context(headers: &HeaderMap, propagation: &PropagationConfig) -> Option<SpanContext> {
     // Try W3C Trace Context first (most common)
-    if let Some(traceparent) = headers.get("traceparent")
+    if propagation.trace_context
+        && let Some(traceparent) = headers.get("traceparent")
         && let Ok(traceparent_str) = traceparent.to_str()
         && let Some(context) = parse_traceparent(traceparent_str)
     {
@@ -156,14 +177,15 @@ fn extract_trace_context(headers: &HeaderMap) -> Option<SpanContext> {
 
     // Try AWS X-Ray format
     // Format: X-Amzn-Trace-Id: Root=1-5759e988-bd862e3fe1be46a994272793;Parent=53995c3f42cd8ad8;Sampled=1
-    if let Some(xray_header) = headers.get("x-amzn-trace-id")
+    if propagation.aws_xray
+        && let Some(xray_header) = headers.get("x-amzn-trace-id")
         && let Ok(xray_str) = xray_header.to_str()
         && let Some(context) = parse_xray_trace_id(xray_str)
     {
         return Some(context);
     }
 
-    // Note: Baggage doesn't carry trace context, only additional metadata
+    // Note: Baggage doesn't carry trace context, only additional metadata; see `parse_w3c_baggage`.
     // Jaeger would be added here if needed
 
     None
@@ -3,8 +3,10 @@
 //! Provides OpenTelemetry metrics, tracing, and logging integration.
 
 pub mod metrics;
+pub mod propagation;
 pub mod tracing;
 
+use anyhow::Context;
 use config::TelemetryConfig;
 use opentelemetry_sdk::metrics::SdkMeterProvider;
 
@@ -19,7 +21,9 @@ pub use opentelemetry::{
 /// Guard that ensures proper cleanup of telemetry resources
 pub struct TelemetryGuard {
     meter_provider: SdkMeterProvider,
+    prometheus_registry: Option<prometheus::Registry>,
     _tracing_guard: Option<tracing::TracingGuard>,
+    host_metrics_task: Option<tokio::task::JoinHandle<()>>,
 }
 
 impl TelemetryGuard {
@@ -38,6 +42,23 @@ impl TelemetryGuard {
 
         Ok(())
     }
+
+    /// Render the current metrics snapshot in Prometheus text exposition
+    /// format, for a scrape endpoint to serve. Returns an error if the
+    /// Prometheus exporter wasn't enabled in the telemetry config.
+    pub fn encode_prometheus_metrics(&self) -> anyhow::Result<String> {
+        use prometheus::Encoder;
+
+        let registry = self
+            .prometheus_registry
+            .as_ref()
+            .context("Prometheus exporter is not enabled")?;
+
+        let mut buffer = Vec::new();
+        prometheus::TextEncoder::new().encode(&registry.gather(), &mut buffer)?;
+
+        Ok(String::from_utf8(buffer)?)
+    }
 }
 
 impl Drop for TelemetryGuard {
@@ -45,6 +66,10 @@ impl Drop for TelemetryGuard {
         if let Err(e) = self.meter_provider.shutdown() {
             log::error!("Failed to shutdown meter provider: {e}");
         }
+
+        if let Some(task) = self.host_metrics_task.take() {
+            task.abort();
+        }
         // Tracing guard will clean up on drop automatically
     }
 }
@@ -57,7 +82,11 @@ pub async fn init(config: &TelemetryConfig) -> anyhow::Result<TelemetryGuard> {
     log::debug!("Telemetry config: tracing enabled = {}", config.tracing().enabled);
 
     // Initialize metrics if enabled
-    let meter_provider = metrics::init_metrics(config).await?;
+    let metrics::MetricsInit {
+        meter_provider,
+        prometheus_registry,
+        host_metrics_task,
+    } = metrics::init_metrics(config).await?;
 
     // Initialize tracing if enabled
     let tracing_guard = if config.tracing().enabled {
@@ -70,6 +99,8 @@ pub async fn init(config: &TelemetryConfig) -> anyhow::Result<TelemetryGuard> {
 
     Ok(TelemetryGuard {
         meter_provider,
+        prometheus_registry,
         _tracing_guard: tracing_guard,
+        host_metrics_task,
     })
 }
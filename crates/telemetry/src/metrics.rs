@@ -1,7 +1,9 @@
 //! Metrics initialization and management
 
+mod host;
 mod names;
 mod recorder;
+mod views;
 
 pub use names::*;
 pub use recorder::Recorder;
@@ -14,6 +16,8 @@ use opentelemetry_sdk::{
     Resource,
     metrics::{PeriodicReader, SdkMeterProvider},
 };
+use prometheus::Registry;
+use tokio::task::JoinHandle;
 
 const METER_NAME: &str = "nexus";
 
@@ -22,83 +26,153 @@ pub fn meter() -> Meter {
     opentelemetry::global::meter(METER_NAME)
 }
 
+/// Result of initializing the metrics subsystem.
+pub(crate) struct MetricsInit {
+    pub(crate) meter_provider: SdkMeterProvider,
+    /// The Prometheus registry backing the scrape endpoint, if that exporter is enabled.
+    pub(crate) prometheus_registry: Option<Registry>,
+    /// Handle to the periodic host/process metrics collector, if enabled.
+    pub(crate) host_metrics_task: Option<JoinHandle<()>>,
+}
+
 /// Initialize the metrics subsystem
-pub(crate) async fn init_metrics(config: &TelemetryConfig) -> anyhow::Result<SdkMeterProvider> {
-    let meter_provider = create_otlp_meter_provider(config).await?;
+pub(crate) async fn init_metrics(config: &TelemetryConfig) -> anyhow::Result<MetricsInit> {
+    let MetricsInit {
+        meter_provider,
+        prometheus_registry,
+        host_metrics_task: _,
+    } = create_meter_provider(config).await?;
 
     // Set as global meter provider
     opentelemetry::global::set_meter_provider(meter_provider.clone());
 
+    // Host/process metrics use the meter obtained from `super::meter()`, which reads the
+    // just-installed global provider, so this must happen after `set_meter_provider` above.
+    let host_metrics_task = host::spawn_host_metrics_collector(config);
+
     log::info!(
         "Telemetry metrics initialized for service '{}'",
         config.service_name().unwrap_or("nexus")
     );
 
-    Ok(meter_provider)
+    Ok(MetricsInit {
+        meter_provider,
+        prometheus_registry,
+        host_metrics_task,
+    })
 }
 
-/// Create an OTLP meter provider
-async fn create_otlp_meter_provider(telemetry_config: &TelemetryConfig) -> anyhow::Result<SdkMeterProvider> {
-    let Some(exporter_config) = telemetry_config.metrics_otlp_config() else {
+/// Create a meter provider with a reader for every enabled metrics exporter
+/// (OTLP push, Prometheus pull, stdout, or any combination of them). Each
+/// exporter is wired up independently, so enabling one doesn't affect the
+/// others - the `mcp.tool.call.duration` histogram and its attributes are
+/// emitted identically to every enabled backend.
+async fn create_meter_provider(telemetry_config: &TelemetryConfig) -> anyhow::Result<MetricsInit> {
+    let otlp_config = telemetry_config.metrics_otlp_config();
+    let prometheus_config = telemetry_config.metrics_prometheus_config();
+    let stdout_config = telemetry_config.metrics_stdout_config();
+
+    if otlp_config.is_none() && prometheus_config.is_none() && stdout_config.is_none() {
         log::debug!("No metrics exporters configured or enabled, metrics will not be exported");
-        return Ok(create_noop_meter_provider());
-    };
 
-    log::debug!(
-        "Initializing OTLP metrics exporter to {} via {:?}",
-        exporter_config.endpoint,
-        exporter_config.protocol
-    );
+        return Ok(MetricsInit {
+            meter_provider: create_noop_meter_provider(),
+            prometheus_registry: None,
+            host_metrics_task: None,
+        });
+    }
 
     // Build resource with service name
-    let mut builder = Resource::builder();
+    let mut resource_builder = Resource::builder();
 
     if let Some(service_name) = telemetry_config.service_name() {
-        builder = builder.with_service_name(service_name.to_string());
+        resource_builder = resource_builder.with_service_name(service_name.to_string());
     }
 
     // Add custom resource attributes
     for (key, value) in telemetry_config.resource_attributes() {
         use opentelemetry::{Key, KeyValue, Value};
-        builder = builder.with_attribute(KeyValue::new(Key::from(key.clone()), Value::from(value.clone())));
+        resource_builder = resource_builder.with_attribute(KeyValue::new(Key::from(key.clone()), Value::from(value.clone())));
     }
 
-    let resource = builder.build();
+    let mut provider_builder = SdkMeterProvider::builder().with_resource(resource_builder.build());
 
-    // Create the OTLP exporter based on protocol
-    let exporter = match exporter_config.protocol {
-        OtlpProtocol::Grpc => MetricExporter::builder()
-            .with_tonic()
-            .with_endpoint(exporter_config.endpoint.as_str())
-            .with_timeout(exporter_config.timeout)
-            .build()
-            .context("Failed to create gRPC OTLP metric exporter")?,
-        OtlpProtocol::Http => MetricExporter::builder()
-            .with_http()
-            .with_endpoint(exporter_config.endpoint.as_str())
-            .with_timeout(exporter_config.timeout)
+    for view in views::build_views(telemetry_config.metrics_views()) {
+        provider_builder = provider_builder.with_view(view);
+    }
+
+    if let Some(exporter_config) = otlp_config {
+        log::debug!(
+            "Initializing OTLP metrics exporter to {} via {:?}",
+            exporter_config.endpoint,
+            exporter_config.protocol
+        );
+
+        // Create the OTLP exporter based on protocol
+        let exporter = match exporter_config.protocol {
+            OtlpProtocol::Grpc => MetricExporter::builder()
+                .with_tonic()
+                .with_endpoint(exporter_config.endpoint.as_str())
+                .with_timeout(exporter_config.timeout)
+                .build()
+                .context("Failed to create gRPC OTLP metric exporter")?,
+            OtlpProtocol::Http => MetricExporter::builder()
+                .with_http()
+                .with_endpoint(exporter_config.endpoint.as_str())
+                .with_timeout(exporter_config.timeout)
+                .build()
+                .context("Failed to create HTTP OTLP metric exporter")?,
+        };
+
+        // Create a periodic reader with the configured batch settings
+        let reader = PeriodicReader::builder(exporter)
+            .with_interval(exporter_config.batch_export.scheduled_delay)
+            .build();
+
+        provider_builder = provider_builder.with_reader(reader);
+
+        log::info!(
+            "OTLP metrics exporter initialized to {} via {:?}",
+            exporter_config.endpoint,
+            exporter_config.protocol
+        );
+    }
+
+    let prometheus_registry = if let Some(prometheus_config) = prometheus_config {
+        log::debug!("Initializing Prometheus scrape endpoint at {}", prometheus_config.path);
+
+        let registry = Registry::new();
+
+        let exporter = opentelemetry_prometheus::exporter()
+            .with_registry(registry.clone())
             .build()
-            .context("Failed to create HTTP OTLP metric exporter")?,
+            .context("Failed to create Prometheus metrics exporter")?;
+
+        provider_builder = provider_builder.with_reader(exporter);
+
+        log::info!("Prometheus scrape endpoint ready at path {}", prometheus_config.path);
+
+        Some(registry)
+    } else {
+        None
     };
 
-    // Create a periodic reader with the configured batch settings
-    let reader = PeriodicReader::builder(exporter)
-        .with_interval(exporter_config.batch_export.scheduled_delay)
-        .build();
+    if stdout_config.is_some() {
+        log::debug!("Initializing stdout metrics exporter");
 
-    // Build the meter provider with resource
-    let provider = SdkMeterProvider::builder()
-        .with_resource(resource)
-        .with_reader(reader)
-        .build();
+        let exporter = opentelemetry_stdout::MetricExporter::builder().build();
+        let reader = PeriodicReader::builder(exporter).build();
 
-    log::info!(
-        "OTLP metrics exporter initialized to {} via {:?}",
-        exporter_config.endpoint,
-        exporter_config.protocol
-    );
+        provider_builder = provider_builder.with_reader(reader);
+
+        log::info!("Stdout metrics exporter initialized");
+    }
 
-    Ok(provider)
+    Ok(MetricsInit {
+        meter_provider: provider_builder.build(),
+        prometheus_registry,
+        host_metrics_task: None,
+    })
 }
 
 /// Create a no-op meter provider (metrics are recorded but not exported)
@@ -0,0 +1,111 @@
+//! Periodic host and process resource metrics
+//!
+//! Samples system/process state on a fixed interval and records it to
+//! gauges obtained from the shared [`super::meter`], so the readings carry
+//! the same service name and resource attributes as the rest of the
+//! metrics subsystem.
+
+use config::TelemetryConfig;
+use opentelemetry::KeyValue;
+use sysinfo::{Networks, Pid, System};
+use tokio::task::JoinHandle;
+
+/// Start the periodic host/process metrics collector, if enabled in config.
+///
+/// Returns `None` (and starts nothing) when host metrics are disabled.
+pub(crate) fn spawn_host_metrics_collector(config: &TelemetryConfig) -> Option<JoinHandle<()>> {
+    let host_metrics = config.host_metrics();
+
+    if !host_metrics.enabled {
+        log::debug!("Host metrics collection is disabled");
+        return None;
+    }
+
+    let collectors = host_metrics.collectors.clone();
+    let interval = host_metrics.interval;
+    let pid = Pid::from_u32(std::process::id());
+
+    let meter = super::meter();
+
+    let cpu_gauge = collectors.cpu.then(|| meter.f64_gauge(super::SYSTEM_CPU_UTILIZATION).build());
+    let memory_gauge = collectors.memory.then(|| meter.u64_gauge(super::SYSTEM_MEMORY_USAGE).build());
+    let process_memory_gauge = collectors.process.then(|| meter.u64_gauge(super::PROCESS_MEMORY_USAGE).build());
+    let process_fd_gauge = collectors
+        .process
+        .then(|| meter.u64_gauge(super::PROCESS_OPEN_FILE_DESCRIPTOR_COUNT).build());
+    let network_gauge = collectors.network.then(|| meter.u64_gauge(super::SYSTEM_NETWORK_IO).build());
+    let disk_gauge = collectors.disk.then(|| meter.u64_gauge(super::PROCESS_DISK_IO).build());
+
+    log::info!("Host metrics collector started with interval {:?}", interval);
+
+    Some(tokio::spawn(async move {
+        let mut system = System::new_all();
+        let mut networks = Networks::new_with_refreshed_list();
+        let mut ticker = tokio::time::interval(interval);
+
+        loop {
+            ticker.tick().await;
+
+            system.refresh_cpu_usage();
+            system.refresh_memory();
+            system.refresh_processes(sysinfo::ProcessesToUpdate::Some(&[pid]), true);
+
+            if let Some(ref gauge) = cpu_gauge {
+                gauge.record(f64::from(system.global_cpu_usage()) / 100.0, &[]);
+            }
+
+            if let Some(ref gauge) = memory_gauge {
+                gauge.record(system.used_memory(), &[KeyValue::new("state", "used")]);
+                gauge.record(system.available_memory(), &[KeyValue::new("state", "available")]);
+                gauge.record(system.total_memory(), &[KeyValue::new("state", "total")]);
+            }
+
+            if let Some(process) = system.process(pid) {
+                if let Some(ref gauge) = process_memory_gauge {
+                    gauge.record(process.memory(), &[]);
+                }
+
+                if let Some(ref gauge) = process_fd_gauge
+                    && let Some(count) = open_file_descriptor_count(pid)
+                {
+                    gauge.record(count, &[]);
+                }
+
+                if let Some(ref gauge) = disk_gauge {
+                    let usage = process.disk_usage();
+                    gauge.record(usage.total_read_bytes, &[KeyValue::new("direction", "read")]);
+                    gauge.record(usage.total_written_bytes, &[KeyValue::new("direction", "write")]);
+                }
+            }
+
+            if let Some(ref gauge) = network_gauge {
+                networks.refresh(true);
+
+                for (device, data) in &networks {
+                    gauge.record(
+                        data.total_received(),
+                        &[KeyValue::new("device", device.clone()), KeyValue::new("direction", "receive")],
+                    );
+                    gauge.record(
+                        data.total_transmitted(),
+                        &[KeyValue::new("device", device.clone()), KeyValue::new("direction", "transmit")],
+                    );
+                }
+            }
+        }
+    }))
+}
+
+/// Count the open file descriptors for a process. Only implemented on Linux,
+/// where `/proc/<pid>/fd` is available; other platforms don't have a
+/// portable equivalent via `sysinfo`.
+#[cfg(target_os = "linux")]
+fn open_file_descriptor_count(pid: Pid) -> Option<u64> {
+    let count = std::fs::read_dir(format!("/proc/{}/fd", pid.as_u32())).ok()?.count();
+    Some(count as u64)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn open_file_descriptor_count(_pid: Pid) -> Option<u64> {
+    None
+}
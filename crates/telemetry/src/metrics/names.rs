@@ -41,3 +41,23 @@ pub const GEN_AI_CLIENT_TOTAL_TOKEN_USAGE: &str = "gen_ai.client.total.token.usa
 /// Time to first token in milliseconds (streaming only)
 /// Tracks the duration until the first token is received in a streaming response
 pub const GEN_AI_CLIENT_TIME_TO_FIRST_TOKEN: &str = "gen_ai.client.time_to_first_token";
+
+/// Host CPU utilization ratio (0.0 to 1.0), averaged across all logical CPUs
+/// See: https://opentelemetry.io/docs/specs/semconv/system/system-metrics/
+pub const SYSTEM_CPU_UTILIZATION: &str = "system.cpu.utilization";
+
+/// Host memory usage in bytes, broken down by `state` (used/available/total)
+pub const SYSTEM_MEMORY_USAGE: &str = "system.memory.usage";
+
+/// Gateway process resident set size in bytes
+/// See: https://opentelemetry.io/docs/specs/semconv/system/process-metrics/
+pub const PROCESS_MEMORY_USAGE: &str = "process.memory.usage";
+
+/// Number of file descriptors currently open by the gateway process
+pub const PROCESS_OPEN_FILE_DESCRIPTOR_COUNT: &str = "process.open_file_descriptor.count";
+
+/// Network bytes transferred, broken down by `device` and `direction` (receive/transmit)
+pub const SYSTEM_NETWORK_IO: &str = "system.network.io";
+
+/// Gateway process disk bytes transferred, broken down by `direction` (read/write)
+pub const PROCESS_DISK_IO: &str = "process.disk.io";
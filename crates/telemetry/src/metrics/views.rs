@@ -0,0 +1,106 @@
+//! Custom OpenTelemetry views for tuning bucket boundaries and attribute
+//! cardinality on specific instruments, per `[[telemetry.metrics.views]]`.
+
+use config::MetricViewConfig;
+use opentelemetry::Key;
+use opentelemetry_sdk::metrics::{Aggregation, Instrument, Stream, View, new_view};
+
+/// The full attribute key set Nexus attaches to each duration histogram,
+/// used to turn a `drop_attributes` list into the `allowed_attribute_keys`
+/// the SDK actually supports. Instruments not listed here only honor
+/// `allowed_attributes` when it's set explicitly, since we have no way to
+/// compute a complement without knowing their full attribute set.
+const KNOWN_INSTRUMENT_ATTRIBUTES: &[(&str, &[&str])] = &[
+    (
+        super::HTTP_SERVER_REQUEST_DURATION,
+        &["http.request.method", "http.route", "http.response.status_code"],
+    ),
+    (
+        super::MCP_TOOL_CALL_DURATION,
+        &[
+            "client.id",
+            "client.group",
+            "tool_type",
+            "tool_name",
+            "server_name",
+            "keyword_count",
+            "result_count",
+            "status",
+            "error.type",
+        ],
+    ),
+    (
+        super::MCP_TOOLS_LIST_DURATION,
+        &["client.id", "client.group", "method", "status", "error.type"],
+    ),
+    (
+        super::MCP_PROMPT_REQUEST_DURATION,
+        &["client.id", "client.group", "method", "status", "error.type"],
+    ),
+    (
+        super::MCP_RESOURCE_REQUEST_DURATION,
+        &["client.id", "client.group", "method", "status", "error.type"],
+    ),
+];
+
+/// Build the OpenTelemetry views for the configured `[[telemetry.metrics.views]]` entries.
+///
+/// Entries that fail to build (e.g. an invalid wildcard pattern) are skipped with a warning
+/// rather than failing telemetry initialization entirely.
+pub(crate) fn build_views(configs: &[MetricViewConfig]) -> Vec<Box<dyn View>> {
+    configs
+        .iter()
+        .filter_map(|config| match build_view(config) {
+            Ok(view) => Some(view),
+            Err(e) => {
+                log::warn!("Skipping invalid metric view for '{}': {e}", config.name);
+                None
+            }
+        })
+        .collect()
+}
+
+fn build_view(config: &MetricViewConfig) -> Result<Box<dyn View>, Box<dyn std::error::Error>> {
+    let instrument = Instrument::new().name(config.name.clone());
+
+    let mut stream = Stream::new();
+
+    if let Some(ref boundaries) = config.bucket_boundaries {
+        stream = stream.aggregation(Aggregation::ExplicitBucketHistogram {
+            boundaries: boundaries.clone(),
+            record_min_max: true,
+        });
+    }
+
+    if let Some(allowed_keys) = allowed_attribute_keys(config) {
+        stream = stream.allowed_attribute_keys(allowed_keys);
+    }
+
+    new_view(instrument, stream)
+}
+
+/// Resolve the effective allowlist for a view: an explicit `allowed_attributes`
+/// always wins, otherwise `drop_attributes` is subtracted from the known
+/// attribute set for that instrument name, if we have one on file.
+fn allowed_attribute_keys(config: &MetricViewConfig) -> Option<Vec<Key>> {
+    if let Some(ref allowed) = config.allowed_attributes {
+        return Some(allowed.iter().cloned().map(Key::from).collect());
+    }
+
+    if config.drop_attributes.is_empty() {
+        return None;
+    }
+
+    let known = KNOWN_INSTRUMENT_ATTRIBUTES
+        .iter()
+        .find(|(name, _)| *name == config.name)
+        .map(|(_, attrs)| *attrs)?;
+
+    Some(
+        known
+            .iter()
+            .filter(|key| !config.drop_attributes.iter().any(|dropped| dropped == *key))
+            .map(|key| Key::from(*key))
+            .collect(),
+    )
+}
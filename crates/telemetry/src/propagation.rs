@@ -0,0 +1,51 @@
+//! W3C Trace Context and Baggage propagation.
+//!
+//! Baggage carries request-scoped metadata (not trace identifiers) alongside a
+//! trace, so it can be turned into span attributes by whichever service
+//! receives it. See <https://www.w3.org/TR/baggage/>.
+
+use fastrace::collector::SpanContext;
+
+/// Baggage entries extracted from (or destined for) a `baggage` HTTP header.
+#[derive(Debug, Clone, Default)]
+pub struct Baggage(pub Vec<(String, String)>);
+
+/// Parse a W3C `baggage` header value into key-value pairs.
+///
+/// Format: `key1=value1,key2=value2;property1`. Properties (the part after a
+/// `;` in a list member) aren't propagated as span attributes.
+pub fn parse_w3c_baggage(header_value: &str) -> Option<Baggage> {
+    let entries: Vec<_> = header_value
+        .split(',')
+        .filter_map(|member| {
+            let (kv, _properties) = member.split_once(';').unwrap_or((member, ""));
+            let (key, value) = kv.split_once('=')?;
+
+            Some((key.trim().to_string(), value.trim().to_string()))
+        })
+        .collect();
+
+    if entries.is_empty() { None } else { Some(Baggage(entries)) }
+}
+
+/// Format baggage entries back into a W3C `baggage` header value, for re-injecting
+/// them into outbound requests made on behalf of the current trace.
+pub fn format_w3c_baggage(baggage: &Baggage) -> Option<String> {
+    if baggage.0.is_empty() {
+        return None;
+    }
+
+    let members: Vec<_> = baggage.0.iter().map(|(key, value)| format!("{key}={value}")).collect();
+
+    Some(members.join(","))
+}
+
+/// Format a trace context as a W3C `traceparent` header value, for re-injecting
+/// trace context into outbound requests made on behalf of the current trace.
+///
+/// Format: `version-trace_id-parent_id-trace_flags`, always using a fixed `00`
+/// version and the sampled (`01`) trace flag, matching what `decode_w3c_traceparent`
+/// on the inbound side accepts.
+pub fn format_w3c_traceparent(context: &SpanContext) -> String {
+    format!("00-{:032x}-{:016x}-01", context.trace_id.0, context.span_id.0)
+}
@@ -101,8 +101,9 @@ pub async fn init_tracing(config: &TelemetryConfig) -> anyhow::Result<TracingGua
 
     fastrace::set_reporter(otel_reporter, collector_config);
 
-    // Note: Trace context propagation from incoming requests is handled at the HTTP middleware level
-    // We don't need OpenTelemetry propagators since we're not making outgoing traced requests
+    // Note: trace context and baggage extraction from incoming requests is handled at the HTTP
+    // middleware level (`server::tracing`), which hands the resulting `SpanContext`/`Baggage` to
+    // downstream handlers via request extensions rather than OpenTelemetry's global propagators.
 
     log::info!(
         "Tracing subsystem initialized successfully with service name: {}",